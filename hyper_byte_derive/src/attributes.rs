@@ -0,0 +1,196 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Expr, LitByteStr, LitInt, LitStr};
+
+/// The byte order used for the primitive fields
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Endian {
+    Big,
+    Little,
+    Native,
+}
+
+impl Endian {
+    fn parse(value: &LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "be" | "big" => Ok(Endian::Big),
+            "le" | "little" => Ok(Endian::Little),
+            "ne" | "native" => Ok(Endian::Native),
+            _ => Err(syn::Error::new(value.span(), "expected an endian of \"be\", \"le\" or \"ne\"")),
+        }
+    }
+
+    /// The suffix of the reader and writer functions, such as the `be` of `read_u32_be`
+    pub(crate) fn suffix(self) -> &'static str {
+        match self {
+            Endian::Big => "be",
+            Endian::Little => "le",
+            Endian::Native => "ne",
+        }
+    }
+
+    pub(crate) fn reader_trait(self) -> TokenStream {
+        match self {
+            Endian::Big => quote!(::hyper_byte::readers::traits::BigEndianByteReader),
+            Endian::Little => quote!(::hyper_byte::readers::traits::LittleEndianByteReader),
+            Endian::Native => quote!(::hyper_byte::readers::traits::NativeEndianByteReader),
+        }
+    }
+
+    pub(crate) fn writer_trait(self) -> TokenStream {
+        match self {
+            Endian::Big => quote!(::hyper_byte::writers::traits::BigEndianByteWriter),
+            Endian::Little => quote!(::hyper_byte::writers::traits::LittleEndianByteWriter),
+            Endian::Native => quote!(::hyper_byte::writers::traits::NativeEndianByteWriter),
+        }
+    }
+}
+
+/// The type the tag of an enum is stored as
+#[derive(Clone, Copy)]
+pub(crate) enum TagType {
+    U8,
+    U16,
+    U32,
+    U64,
+    /// An unsigned LEB128 variable-length integer
+    Varint,
+}
+
+impl TagType {
+    fn parse(value: &LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "u8" => Ok(TagType::U8),
+            "u16" => Ok(TagType::U16),
+            "u32" => Ok(TagType::U32),
+            "u64" => Ok(TagType::U64),
+            "varint" => Ok(TagType::Varint),
+            _ => Err(syn::Error::new(
+                value.span(),
+                "expected a tag type of \"u8\", \"u16\", \"u32\", \"u64\" or \"varint\"",
+            )),
+        }
+    }
+
+    /// The largest tag the type can hold
+    pub(crate) fn maximum(self) -> u64 {
+        match self {
+            TagType::U8 => u8::MAX as u64,
+            TagType::U16 => u16::MAX as u64,
+            TagType::U32 => u32::MAX as u64,
+            TagType::U64 | TagType::Varint => u64::MAX,
+        }
+    }
+}
+
+/// The `#[hyper(...)]` attributes on the struct or enum itself
+pub(crate) struct ContainerAttributes {
+    pub(crate) endian: Endian,
+    /// The type of the tag of an enum, which is a `u8` if left out
+    pub(crate) tag_type: Option<LitStr>,
+}
+
+impl ContainerAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut endian = Endian::Big;
+        let mut tag_type = None;
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("hyper")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("endian") {
+                    endian = Endian::parse(&meta.value()?.parse()?)?;
+                    Ok(())
+                } else if meta.path.is_ident("tag_type") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    TagType::parse(&value)?;
+                    tag_type = Some(value);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown hyper attribute, expected `endian` or `tag_type`"))
+                }
+            })?;
+        }
+        Ok(ContainerAttributes { endian, tag_type })
+    }
+
+    pub(crate) fn tag_type(&self) -> syn::Result<TagType> {
+        self.tag_type.as_ref().map_or(Ok(TagType::U8), TagType::parse)
+    }
+}
+
+/// The `#[hyper(...)]` attributes on a variant of an enum
+#[derive(Default)]
+pub(crate) struct VariantAttributes {
+    /// The tag of the variant, when it is not given as its discriminant
+    pub(crate) tag: Option<LitInt>,
+    /// Whether the variant holds any unknown tag, along with the rest of the bytes
+    pub(crate) unknown: bool,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut variant = VariantAttributes::default();
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("hyper")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    variant.tag = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("unknown") {
+                    variant.unknown = true;
+                } else {
+                    return Err(meta.error("unknown hyper attribute, expected `tag` or `unknown`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(variant)
+    }
+}
+
+/// The `#[hyper(...)]` attributes on a single field
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// Overrides the endian of the container for this field
+    pub(crate) endian: Option<Endian>,
+    /// The amount of elements of a `Vec`, or bytes of a `String`, usually an earlier field
+    pub(crate) length: Option<Expr>,
+    /// The constant bytes the field must hold
+    pub(crate) magic: Option<LitByteStr>,
+    /// Bytes of padding before the field
+    pub(crate) pad: Option<LitInt>,
+    /// Whether the field is left out, and filled with its default when read
+    pub(crate) skip: bool,
+    /// Whether an `Option` field is present
+    pub(crate) condition: Option<Expr>,
+}
+
+impl FieldAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut field = FieldAttributes::default();
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("hyper")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("be") {
+                    field.endian = Some(Endian::Big);
+                } else if meta.path.is_ident("le") {
+                    field.endian = Some(Endian::Little);
+                } else if meta.path.is_ident("ne") {
+                    field.endian = Some(Endian::Native);
+                } else if meta.path.is_ident("len") {
+                    field.length = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("magic") {
+                    field.magic = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("pad") {
+                    field.pad = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    field.skip = true;
+                } else if meta.path.is_ident("if") {
+                    field.condition = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown hyper attribute, expected `be`, `le`, `ne`, `len`, `magic`, `pad`, `skip` or `if`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(field)
+    }
+}
@@ -0,0 +1,179 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, Expr, ExprLit, Fields, Lit, LitInt, Variant};
+use crate::attributes::{ContainerAttributes, Endian, TagType, VariantAttributes};
+use crate::fields::{pattern, read_fields, write_fields, Bounds};
+
+impl TagType {
+    /// The integer type the tag is held in
+    fn rust_type(self) -> Ident {
+        match self {
+            TagType::U8 => format_ident!("u8"),
+            TagType::U16 => format_ident!("u16"),
+            TagType::U32 => format_ident!("u32"),
+            TagType::U64 | TagType::Varint => format_ident!("u64"),
+        }
+    }
+
+    /// Reads the tag as a `u64`
+    fn read(self, endian: Endian) -> TokenStream {
+        match self {
+            TagType::Varint => quote!(::hyper_byte::readers::traits::ByteReader::read_varint_u64(__reader)?),
+            _ => {
+                let reader_trait = endian.reader_trait();
+                let function = format_ident!("read_{}_{}", self.rust_type(), endian.suffix());
+                quote!(#reader_trait::#function(__reader) as u64)
+            }
+        }
+    }
+
+    /// Writes the tag from `value`, which is of [`TagType::rust_type`]
+    fn write(self, endian: Endian, value: TokenStream) -> TokenStream {
+        match self {
+            TagType::Varint => quote!(::hyper_byte::writers::traits::ByteWriter::write_varint_u64(__writer, #value);),
+            _ => {
+                let writer_trait = endian.writer_trait();
+                let function = format_ident!("write_{}_{}", self.rust_type(), endian.suffix());
+                quote!(#writer_trait::#function(__writer, #value);)
+            }
+        }
+    }
+}
+
+/// A variant with a tag of its own
+struct Tagged<'a> {
+    variant: &'a Variant,
+    tag: u64,
+}
+
+/// The variants of an enum, with the catch-all variant holding any unknown tag kept apart
+struct Variants<'a> {
+    tagged: Vec<Tagged<'a>>,
+    unknown: Option<&'a Variant>,
+}
+
+fn literal_tag(literal: &LitInt, tag_type: TagType) -> syn::Result<u64> {
+    let tag = literal.base10_parse::<u64>()?;
+    if tag > tag_type.maximum() {
+        return Err(syn::Error::new(literal.span(), "the tag does not fit in the tag type"));
+    }
+    Ok(tag)
+}
+
+fn parse_variants(data: &DataEnum, tag_type: TagType) -> syn::Result<Variants<'_>> {
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(Span::call_site(), "HyperRead and HyperWrite cannot be derived for an enum without variants"));
+    }
+    let mut variants = Variants { tagged: Vec::new(), unknown: None };
+    let mut next = Some(0u64);
+    for variant in &data.variants {
+        let attributes = VariantAttributes::parse(&variant.attrs)?;
+        if attributes.unknown {
+            if variants.unknown.is_some() {
+                return Err(syn::Error::new_spanned(variant, "only one variant can be `unknown`"));
+            }
+            if !matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 2) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "the `unknown` variant must hold the tag and the remaining bytes, such as `Unknown(u16, Vec<u8>)`",
+                ));
+            }
+            variants.unknown = Some(variant);
+            continue;
+        }
+        let tag = match (&attributes.tag, &variant.discriminant) {
+            (Some(literal), _) => literal_tag(literal, tag_type)?,
+            (None, Some((_, Expr::Lit(ExprLit { lit: Lit::Int(literal), .. })))) => literal_tag(literal, tag_type)?,
+            (None, Some((_, discriminant))) => {
+                return Err(syn::Error::new_spanned(
+                    discriminant,
+                    "the discriminant must be an integer literal, or the tag given with `#[hyper(tag = ...)]`",
+                ));
+            }
+            (None, None) => match next {
+                Some(tag) if tag <= tag_type.maximum() => tag,
+                _ => return Err(syn::Error::new_spanned(variant, "the tag does not fit in the tag type")),
+            },
+        };
+        if variants.tagged.iter().any(|tagged| tagged.tag == tag) {
+            return Err(syn::Error::new_spanned(variant, format!("the tag {tag} is used by another variant")));
+        }
+        next = tag.checked_add(1);
+        variants.tagged.push(Tagged { variant, tag });
+    }
+    Ok(variants)
+}
+
+/// The body of `hyper_read`, reading the tag and then the fields of the variant it belongs to
+pub(crate) fn read_variants(data: &DataEnum, attributes: &ContainerAttributes, bounds: &mut Bounds) -> syn::Result<TokenStream> {
+    let tag_type = attributes.tag_type()?;
+    let variants = parse_variants(data, tag_type)?;
+    let mut arms = Vec::new();
+    for Tagged { variant, tag } in &variants.tagged {
+        let ident = &variant.ident;
+        let tag = LitInt::new(&format!("{tag}u64"), Span::call_site());
+        let (reads, constructor) = read_fields(&variant.fields, attributes.endian, bounds)?;
+        arms.push(quote! {
+            #tag => {
+                #reads
+                ::core::result::Result::Ok(Self::#ident #constructor)
+            }
+        });
+    }
+    let fallback = match variants.unknown {
+        Some(variant) => {
+            let ident = &variant.ident;
+            quote!(::core::result::Result::Ok(Self::#ident(
+                ::core::convert::TryFrom::try_from(__tag).map_err(|_| ::hyper_byte::error::ByteError::OutOfRange)?,
+                ::hyper_byte::derive::read_remaining(__reader),
+            )))
+        }
+        None => quote!(::core::result::Result::Err(::hyper_byte::error::ByteError::UnknownTag { tag: __tag })),
+    };
+    let read_tag = tag_type.read(attributes.endian);
+    Ok(quote! {
+        let __tag: u64 = #read_tag;
+        match __tag {
+            #(#arms)*
+            _ => #fallback,
+        }
+    })
+}
+
+/// The body of `hyper_write`, writing the tag of the variant and then its fields
+pub(crate) fn write_variants(data: &DataEnum, attributes: &ContainerAttributes, bounds: &mut Bounds) -> syn::Result<TokenStream> {
+    let tag_type = attributes.tag_type()?;
+    let variants = parse_variants(data, tag_type)?;
+    let rust_type = tag_type.rust_type();
+    let mut arms = Vec::new();
+    for Tagged { variant, tag } in &variants.tagged {
+        let ident = &variant.ident;
+        let tag = LitInt::new(&format!("{tag}{rust_type}"), Span::call_site());
+        let write_tag = tag_type.write(attributes.endian, quote!(#tag));
+        let pattern = pattern(&variant.fields)?;
+        let writes = write_fields(&variant.fields, attributes.endian, bounds)?;
+        arms.push(quote! {
+            Self::#ident #pattern => {
+                #write_tag
+                #writes
+            }
+        });
+    }
+    if let Some(variant) = variants.unknown {
+        let ident = &variant.ident;
+        let write_tag = tag_type.write(attributes.endian, quote!(__tag));
+        arms.push(quote! {
+            Self::#ident(__tag, __bytes) => {
+                let __tag: #rust_type = ::core::convert::TryFrom::try_from(*__tag)
+                    .map_err(|_| ::hyper_byte::error::ByteError::OutOfRange)?;
+                #write_tag
+                ::hyper_byte::writers::traits::ByteWriter::put_bytes(__writer, __bytes);
+            }
+        });
+    }
+    Ok(quote! {
+        match self {
+            #(#arms)*
+        }
+    })
+}
@@ -0,0 +1,385 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Expr, Fields, GenericArgument, PathArguments, Type, WherePredicate};
+use crate::attributes::{Endian, FieldAttributes};
+
+/// A field type the endian traits can read and write directly
+enum Primitive {
+    /// Any of the numeric types, which all follow the `read_{name}_{endian}` naming
+    Number(Ident),
+    Bool,
+    Char,
+}
+
+impl Primitive {
+    fn of(ty: &Type) -> Option<Self> {
+        let (ident, arguments) = last_segment(ty)?;
+        if !matches!(arguments, PathArguments::None) {
+            return None;
+        }
+        match ident.to_string().as_str() {
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" | "f16" | "bf16" | "f32" | "f64" => Some(Primitive::Number(ident.clone())),
+            "bool" => Some(Primitive::Bool),
+            "char" => Some(Primitive::Char),
+            _ => None,
+        }
+    }
+
+    fn read(&self, endian: Endian) -> TokenStream {
+        let reader_trait = endian.reader_trait();
+        match self {
+            Primitive::Number(name) => {
+                let function = format_ident!("read_{}_{}", name, endian.suffix());
+                quote!(#reader_trait::#function(__reader))
+            }
+            Primitive::Bool => quote!(::hyper_byte::readers::traits::ByteReader::read_bool(__reader)?),
+            Primitive::Char => {
+                let function = format_ident!("read_char_{}", endian.suffix());
+                quote!(#reader_trait::#function(__reader)?)
+            }
+        }
+    }
+
+    fn write(&self, endian: Endian, value: TokenStream) -> TokenStream {
+        let writer_trait = endian.writer_trait();
+        match self {
+            Primitive::Number(name) => {
+                let function = format_ident!("write_{}_{}", name, endian.suffix());
+                quote!(#writer_trait::#function(__writer, #value);)
+            }
+            Primitive::Bool => quote!(::hyper_byte::writers::traits::ByteWriter::write_bool(__writer, #value);),
+            Primitive::Char => {
+                let function = format_ident!("write_char_{}", endian.suffix());
+                quote!(#writer_trait::#function(__writer, #value);)
+            }
+        }
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<(&Ident, &PathArguments)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    Some((&segment.ident, &segment.arguments))
+}
+
+/// The `T` of a type such as `Vec<T>` or `Option<T>`
+fn inner_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let (ident, PathArguments::AngleBracketed(arguments)) = last_segment(ty)? else {
+        return None;
+    };
+    if ident != wrapper || arguments.args.len() != 1 {
+        return None;
+    }
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn is_string(ty: &Type) -> bool {
+    matches!(last_segment(ty), Some((ident, PathArguments::None)) if ident == "String")
+}
+
+/// The bounds the generated implementation needs, beyond the endian of the container
+pub(crate) struct Bounds {
+    pub(crate) endians: Vec<Endian>,
+    pub(crate) predicates: Vec<WherePredicate>,
+}
+
+impl Bounds {
+    pub(crate) fn new(endian: Endian) -> Self {
+        Bounds {
+            endians: vec![endian],
+            predicates: Vec::new(),
+        }
+    }
+
+    fn use_endian(&mut self, endian: Endian) {
+        if !self.endians.contains(&endian) {
+            self.endians.push(endian);
+        }
+    }
+}
+
+/// A field together with its attributes and the name of the local it is read into, or borrowed as when writing
+struct Field<'a> {
+    ty: &'a Type,
+    attributes: FieldAttributes,
+    endian: Endian,
+    local: Ident,
+}
+
+fn parse_fields(fields: &Fields, endian: Endian) -> syn::Result<Vec<Field<'_>>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let attributes = FieldAttributes::parse(&field.attrs)?;
+            Ok(Field {
+                ty: &field.ty,
+                endian: attributes.endian.unwrap_or(endian),
+                attributes,
+                local: match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None => format_ident!("__field_{}", index),
+                },
+            })
+        })
+        .collect()
+}
+
+fn error(tokens: impl ToTokens, message: &str) -> syn::Error {
+    syn::Error::new_spanned(tokens, message)
+}
+
+/// Whether the value is stored with its length first when it has no `len` attribute
+fn is_prefixed(ty: &Type) -> bool {
+    is_string(ty) || inner_type(ty, "Vec").is_some()
+}
+
+/// The `u32` used for the length of a `String` or `Vec` without a `len` attribute, the same as the codec traits
+fn length_prefix() -> Primitive {
+    Primitive::Number(format_ident!("u32"))
+}
+
+/// Reads a value of `ty`, taking the amount of elements from `length` for a `Vec` or `String`, or from the
+/// `u32` before it without one
+fn read_value(ty: &Type, length: Option<&Expr>, endian: Endian, bounds: &mut Bounds) -> syn::Result<TokenStream> {
+    let length = match length {
+        Some(length) => Some(quote!(::hyper_byte::derive::length(#length)?)),
+        None if is_prefixed(ty) => {
+            bounds.use_endian(endian);
+            let read = length_prefix().read(endian);
+            Some(quote!(::hyper_byte::derive::length(#read)?))
+        }
+        None => None,
+    };
+    if let Some(length) = length {
+        // The length is read first, as reading its prefix borrows the reader too
+        return if is_string(ty) {
+            Ok(quote!({
+                let __length = #length;
+                ::hyper_byte::derive::read_string(__reader, __length)?
+            }))
+        } else if let Some(element) = inner_type(ty, "Vec") {
+            let read = read_value(element, None, endian, bounds)?;
+            Ok(quote!({
+                let __length = #length;
+                ::hyper_byte::derive::read_vec(__reader, __length, |__reader: &mut __R| {
+                    let __value = #read;
+                    ::core::result::Result::<_, ::hyper_byte::error::ByteError>::Ok(__value)
+                })?
+            }))
+        } else {
+            Err(error(ty, "`len` can only be used on a `Vec` or a `String`"))
+        };
+    }
+    if let Some(inner) = inner_type(ty, "Option") {
+        let read = read_value(inner, None, endian, bounds)?;
+        return Ok(quote! {
+            if ::hyper_byte::readers::traits::ByteReader::read_bool(__reader)? {
+                ::core::option::Option::Some(#read)
+            } else {
+                ::core::option::Option::None
+            }
+        });
+    }
+    if let Type::Array(array) = ty {
+        let read = read_value(&array.elem, None, endian, bounds)?;
+        return Ok(quote! {
+            ::hyper_byte::derive::read_array(__reader, |__reader: &mut __R| {
+                let __value = #read;
+                ::core::result::Result::<_, ::hyper_byte::error::ByteError>::Ok(__value)
+            })?
+        });
+    }
+    Ok(match Primitive::of(ty) {
+        Some(primitive) => {
+            bounds.use_endian(endian);
+            primitive.read(endian)
+        }
+        None => {
+            bounds.predicates.push(parse_quote!(#ty: ::hyper_byte::derive::HyperRead<__R>));
+            quote!(<#ty as ::hyper_byte::derive::HyperRead<__R>>::hyper_read(__reader)?)
+        }
+    })
+}
+
+/// Writes the value of `ty` behind the reference `value`, after its length for a `Vec` or `String` without
+/// a `len` attribute
+fn write_value(
+    ty: &Type,
+    length: Option<&Expr>,
+    endian: Endian,
+    value: TokenStream,
+    bounds: &mut Bounds,
+) -> syn::Result<TokenStream> {
+    let check = match length {
+        Some(length) => {
+            Some(quote!(::hyper_byte::derive::check_length(::hyper_byte::derive::length(#length)?, #value.len())?;))
+        }
+        None if is_prefixed(ty) => {
+            bounds.use_endian(endian);
+            Some(length_prefix().write(endian, quote!(::hyper_byte::derive::prefix_length(#value.len())?)))
+        }
+        None => None,
+    };
+    if let Some(check) = check {
+        return if is_string(ty) {
+            Ok(quote! {
+                #check
+                ::hyper_byte::writers::traits::ByteWriter::put_bytes(__writer, #value.as_bytes());
+            })
+        } else if let Some(element) = inner_type(ty, "Vec") {
+            let write = write_value(element, None, endian, quote!(__item), bounds)?;
+            Ok(quote! {
+                #check
+                for __item in #value.iter() {
+                    #write
+                }
+            })
+        } else {
+            Err(error(ty, "`len` can only be used on a `Vec` or a `String`"))
+        };
+    }
+    if let Some(inner) = inner_type(ty, "Option") {
+        let write = write_value(inner, None, endian, quote!(__value), bounds)?;
+        return Ok(quote! {
+            ::hyper_byte::writers::traits::ByteWriter::write_bool(__writer, #value.is_some());
+            if let ::core::option::Option::Some(__value) = #value {
+                #write
+            }
+        });
+    }
+    if let Type::Array(array) = ty {
+        let write = write_value(&array.elem, None, endian, quote!(__item), bounds)?;
+        return Ok(quote! {
+            for __item in #value.iter() {
+                #write
+            }
+        });
+    }
+    Ok(match Primitive::of(ty) {
+        Some(primitive) => {
+            bounds.use_endian(endian);
+            primitive.write(endian, quote!(*#value))
+        }
+        None => {
+            bounds.predicates.push(parse_quote!(#ty: ::hyper_byte::derive::HyperWrite<__W>));
+            quote!(<#ty as ::hyper_byte::derive::HyperWrite<__W>>::hyper_write(#value, __writer)?;)
+        }
+    })
+}
+
+/// The statements reading every field into a local named after it, and the fields of the constructor using them,
+/// such as `{ id, name }` or `(__field_0, __field_1)`
+pub(crate) fn read_fields(fields: &Fields, endian: Endian, bounds: &mut Bounds) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut reads = Vec::new();
+    for field in parse_fields(fields, endian)? {
+        let Field { ty, attributes, endian, local } = field;
+        if let Some(pad) = &attributes.pad {
+            reads.push(quote!(::hyper_byte::readers::traits::ByteReader::skip_n(__reader, #pad);));
+        }
+        let read = if attributes.skip {
+            quote!(::core::default::Default::default())
+        } else if let Some(magic) = &attributes.magic {
+            quote!({
+                ::hyper_byte::derive::read_magic(__reader, #magic)?;
+                *#magic
+            })
+        } else if let Some(condition) = &attributes.condition {
+            let inner = inner_type(ty, "Option").ok_or_else(|| error(ty, "`if` can only be used on an `Option`"))?;
+            let read = read_value(inner, attributes.length.as_ref(), endian, bounds)?;
+            quote!(if #condition { ::core::option::Option::Some(#read) } else { ::core::option::Option::None })
+        } else {
+            read_value(ty, attributes.length.as_ref(), endian, bounds)?
+        };
+        reads.push(quote!(let #local: #ty = #read;));
+    }
+    Ok((quote!(#(#reads)*), constructor(fields)))
+}
+
+/// The statements writing every field, from the locals bound by the pattern of [`constructor`].<br/>
+/// The locals of primitive fields are copied out of their references first, so `len` and `if` expressions
+/// see the same values as when reading.
+pub(crate) fn write_fields(fields: &Fields, endian: Endian, bounds: &mut Bounds) -> syn::Result<TokenStream> {
+    let parsed = parse_fields(fields, endian)?;
+    let mut writes = Vec::new();
+    for field in &parsed {
+        if Primitive::of(field.ty).is_some() && !field.attributes.skip {
+            let local = &field.local;
+            writes.push(quote!(let #local = *#local;));
+        }
+    }
+    for field in parsed {
+        let Field { ty, attributes, endian, local } = field;
+        let value = match Primitive::of(ty) {
+            Some(_) => quote!(&#local),
+            None => quote!(#local),
+        };
+        if let Some(pad) = &attributes.pad {
+            writes.push(quote!(::hyper_byte::derive::write_padding(__writer, #pad);));
+        }
+        if attributes.skip {
+            continue;
+        }
+        writes.push(if let Some(magic) = &attributes.magic {
+            quote!(::hyper_byte::writers::traits::ByteWriter::put_bytes(__writer, #magic);)
+        } else if let Some(condition) = &attributes.condition {
+            let inner = inner_type(ty, "Option").ok_or_else(|| error(ty, "`if` can only be used on an `Option`"))?;
+            let write = write_value(inner, attributes.length.as_ref(), endian, quote!(__value), bounds)?;
+            quote! {
+                match (#condition, #value) {
+                    (true, ::core::option::Option::Some(__value)) => {
+                        #write
+                    }
+                    (false, ::core::option::Option::None) => {}
+                    _ => return ::core::result::Result::Err(::hyper_byte::error::ByteError::ConditionMismatch),
+                }
+            }
+        } else {
+            write_value(ty, attributes.length.as_ref(), endian, value, bounds)?
+        });
+    }
+    Ok(quote!(#(#writes)*))
+}
+
+/// The fields of a constructor or pattern, such as `{ id, name }` or `(__field_0, __field_1)`.<br/>
+/// Skipped and magic fields are matched with `_` in a pattern, as nothing is written from them.
+fn constructor(fields: &Fields) -> TokenStream {
+    let locals = fields.iter().enumerate().map(|(index, field)| match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("__field_{}", index),
+    });
+    match fields {
+        Fields::Named(_) => quote!({ #(#locals),* }),
+        Fields::Unnamed(_) => quote!((#(#locals),*)),
+        Fields::Unit => quote!(),
+    }
+}
+
+/// The pattern binding every field by reference for [`write_fields`], leaving out skipped and magic fields
+pub(crate) fn pattern(fields: &Fields) -> syn::Result<TokenStream> {
+    let mut bindings = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        let unused = attributes.skip || attributes.magic.is_some();
+        bindings.push(match (&field.ident, unused) {
+            (Some(ident), false) => quote!(#ident),
+            (Some(ident), true) => quote!(#ident: _),
+            (None, false) => format_ident!("__field_{}", index).into_token_stream(),
+            (None, true) => quote!(_),
+        });
+    }
+    Ok(match fields {
+        Fields::Named(_) => quote!({ #(#bindings),* }),
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        Fields::Unit => quote!(),
+    })
+}
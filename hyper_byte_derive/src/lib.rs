@@ -0,0 +1,134 @@
+//! Derive macros for `hyper_byte`, generating `HyperRead` and `HyperWrite` implementations which
+//! read and write each field in order with the endian reader and writer traits, after a tag for enums,
+//! and `View` implementations for structs viewed in place over bytes.<br/>
+//! Use them through the `derive` feature of `hyper_byte`, which re-exports them next to the traits
+//! in `hyper_byte::derive` and `hyper_byte::view`.
+
+mod attributes;
+mod enums;
+mod fields;
+mod view;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput};
+use attributes::ContainerAttributes;
+use enums::{read_variants, write_variants};
+use fields::{pattern, read_fields, write_fields, Bounds};
+use view::expand_view;
+
+/// Derives `HyperRead` for a struct, reading every field in declaration order, or for an enum, reading the tag
+/// followed by the fields of its variant.<br/>
+/// Primitive fields use the endian given by `#[hyper(endian = "be")]`, which is big-endian if left out,
+/// and every other field is read with its own `HyperRead` implementation. See `hyper_byte::derive`
+/// for the attributes of the fields.
+#[proc_macro_derive(HyperRead, attributes(hyper))]
+pub fn derive_hyper_read(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_read(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `HyperWrite` for a struct, writing every field in declaration order, or for an enum, writing the tag
+/// followed by the fields of its variant.<br/>
+/// Primitive fields use the endian given by `#[hyper(endian = "be")]`, which is big-endian if left out,
+/// and every other field is written with its own `HyperWrite` implementation. See `hyper_byte::derive`
+/// for the attributes of the fields.
+#[proc_macro_derive(HyperWrite, attributes(hyper))]
+pub fn derive_hyper_write(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_write(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `View` for a `#[repr(C)]` struct of which every field is a `View`, such as the wire types of
+/// `hyper_byte::wire`, so it can be viewed in place over bytes. See `hyper_byte::view`.
+#[proc_macro_derive(View)]
+pub fn derive_view(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_view(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Checks the container attributes fit the kind of type being derived for
+fn check_container(input: &DeriveInput, attributes: &ContainerAttributes) -> syn::Result<()> {
+    match (&input.data, &attributes.tag_type) {
+        (Data::Struct(_), Some(tag_type)) => Err(syn::Error::new_spanned(tag_type, "`tag_type` can only be used on an enum")),
+        (Data::Union(_), _) => Err(syn::Error::new(Span::call_site(), "HyperRead and HyperWrite cannot be derived for unions")),
+        _ => Ok(()),
+    }
+}
+
+fn expand_read(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attributes = ContainerAttributes::parse(&input.attrs)?;
+    check_container(input, &attributes)?;
+    let mut bounds = Bounds::new(attributes.endian);
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (reads, constructor) = read_fields(&data.fields, attributes.endian, &mut bounds)?;
+            quote! {
+                #reads
+                ::core::result::Result::Ok(Self #constructor)
+            }
+        }
+        Data::Enum(data) => read_variants(data, &attributes, &mut bounds)?,
+        Data::Union(_) => unreachable!(),
+    };
+
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!(__R: ?Sized));
+    let where_clause = generics.make_where_clause();
+    for endian in &bounds.endians {
+        let reader_trait = endian.reader_trait();
+        where_clause.predicates.push(parse_quote!(__R: #reader_trait));
+    }
+    where_clause.predicates.extend(bounds.predicates);
+
+    let ident = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::hyper_byte::derive::HyperRead<__R> for #ident #type_generics #where_clause {
+            fn hyper_read(__reader: &mut __R) -> ::core::result::Result<Self, ::hyper_byte::error::ByteError> {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_write(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attributes = ContainerAttributes::parse(&input.attrs)?;
+    check_container(input, &attributes)?;
+    let mut bounds = Bounds::new(attributes.endian);
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern(&data.fields)?;
+            let writes = write_fields(&data.fields, attributes.endian, &mut bounds)?;
+            quote! {
+                let Self #pattern = self;
+                #writes
+            }
+        }
+        Data::Enum(data) => write_variants(data, &attributes, &mut bounds)?,
+        Data::Union(_) => unreachable!(),
+    };
+
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!(__W: ?Sized));
+    let where_clause = generics.make_where_clause();
+    for endian in &bounds.endians {
+        let writer_trait = endian.writer_trait();
+        where_clause.predicates.push(parse_quote!(__W: #writer_trait));
+    }
+    where_clause.predicates.extend(bounds.predicates);
+
+    let ident = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::hyper_byte::derive::HyperWrite<__W> for #ident #type_generics #where_clause {
+            fn hyper_write(&self, __writer: &mut __W) -> ::core::result::Result<(), ::hyper_byte::error::ByteError> {
+                #body
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,52 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput};
+
+/// Whether the struct has a `#[repr(...)]` which lays out its fields in order without reordering them
+fn has_ordered_repr(input: &DeriveInput) -> syn::Result<bool> {
+    let mut ordered = false;
+    for attribute in input.attrs.iter().filter(|attribute| attribute.path().is_ident("repr")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") || meta.path.is_ident("packed") {
+                ordered = true;
+            }
+            // Skip the arguments of the others, such as `align(4)`, which the alignment check rejects anyway
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(ordered)
+}
+
+/// Implements `View` for a struct of views, asserting every field is one at compile time.<br/>
+/// With every field having an alignment of 1, a struct in declaration order cannot have any padding.
+pub(crate) fn expand_view(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(Span::call_site(), "View can only be derived for structs"));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "View cannot be derived for generic structs"));
+    }
+    if !has_ordered_repr(input)? {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "View can only be derived for a struct with `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`",
+        ));
+    }
+    let ident = &input.ident;
+    let types = data.fields.iter().map(|field| &field.ty);
+    Ok(quote! {
+        const _: () = {
+            const fn __assert_view<T: ::hyper_byte::view::View>() {}
+            #(__assert_view::<#types>();)*
+            assert!(::core::mem::align_of::<#ident>() == 1, "a View must have an alignment of 1");
+        };
+
+        // SAFETY: The struct keeps its fields in order, and every one of them accepts any bytes with an
+        // alignment of 1, so the struct has no padding and accepts any bytes too
+        unsafe impl ::hyper_byte::view::View for #ident {}
+    })
+}
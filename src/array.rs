@@ -0,0 +1,36 @@
+//! Building arrays an item at a time, where building an item can fail, shared by the codec traits and the
+//! derived implementations.
+
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use crate::error::ByteError;
+
+/// The items of an array built so far, which are dropped if building one of the rest fails
+struct PartialArray<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    initialized: usize,
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for item in &mut self.items[..self.initialized] {
+            // SAFETY: The first `initialized` items were written
+            unsafe { item.assume_init_drop() };
+        }
+    }
+}
+
+/// Builds the array in place from `N` calls of `item`, stopping at the first error
+#[inline(always)]
+pub(crate) fn try_from_fn<T, const N: usize>(
+    mut item: impl FnMut() -> Result<T, ByteError>,
+) -> Result<[T; N], ByteError> {
+    let mut array = PartialArray { items: [const { MaybeUninit::uninit() }; N], initialized: 0 };
+    while array.initialized < N {
+        array.items[array.initialized].write(item()?);
+        array.initialized += 1;
+    }
+    let array = ManuallyDrop::new(array);
+    // SAFETY: Every item was initialized above, and the guard no longer drops them
+    Ok(unsafe { ptr::from_ref(&array.items).cast::<[T; N]>().read() })
+}
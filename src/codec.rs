@@ -0,0 +1,411 @@
+//! Traits for types which can encode themselves with any writer and decode themselves from any reader,
+//! in the byte order `E` given by the markers of [`crate::endian`].<br/>
+//! They are implemented for the primitives, tuples of up to 12 elements, arrays, and the collections of
+//! `alloc`, along with `HashMap` with the `std` feature. Strings, `Vec`s, slices and maps are stored with
+//! their length first, in the form given by the [`LengthPrefix`] `L`, which is a `u32` unless chosen otherwise.
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::codec::{HyperDecode, HyperEncode, VarintPrefix};
+//! use hyper_byte::endian::{EndianReader, EndianWriter, LittleEndian};
+//! use hyper_byte::reader::LittleReader;
+//! use hyper_byte::writer::FastByteWriter;
+//! use hyper_byte::writers::traits::ByteWriter;
+//!
+//! let mut writer = FastByteWriter::new();
+//! EndianWriter::<LittleEndian>::encode(&mut writer, &(7u32, vec![1.5f32, -2.0])).unwrap();
+//! EndianWriter::<LittleEndian>::encode_with::<_, VarintPrefix>(&mut writer, "hi").unwrap();
+//!
+//! let buffer = writer.to_vec();
+//! assert_eq!(buffer[..8], [7, 0, 0, 0, 2, 0, 0, 0]);
+//!
+//! // A reader of a single endian needs no marker
+//! let mut reader = LittleReader::new(&buffer);
+//! assert_eq!(reader.decode::<(u32, Vec<f32>)>().unwrap(), (7, vec![1.5, -2.0]));
+//! assert_eq!(reader.decode_with::<String, VarintPrefix>().unwrap(), "hi");
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use crate::array::try_from_fn;
+use crate::endian::{EndianReader, EndianWriter};
+use crate::error::ByteError;
+use crate::text::read_string;
+use crate::writer::SizeCounter;
+
+/// A type which can be written to any writer of the byte order `E`, with lengths stored as given by `L`
+pub trait HyperEncode<E, L: LengthPrefix = U32Prefix> {
+    /// Writes the value to the end of the writer
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError>;
+
+    /// The exact amount of bytes [`HyperEncode::encode`] writes, found by encoding into a [`SizeCounter`]<br/>
+    /// Errors in the same cases as encoding does, such as a length too long for its prefix
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::codec::HyperEncode;
+    /// use hyper_byte::endian::BigEndian;
+    ///
+    /// let message = (7u32, vec![1.5f32, -2.0], String::from("hi"));
+    /// assert_eq!(HyperEncode::<BigEndian>::encoded_len(&message).unwrap(), 4 + 4 + 8 + 4 + 2);
+    /// ```
+    fn encoded_len(&self) -> Result<usize, ByteError>
+    where
+        SizeCounter: EndianWriter<E>,
+    {
+        let mut counter = SizeCounter::new();
+        self.encode(&mut counter)?;
+        Ok(counter.len())
+    }
+}
+
+/// A type which can be read from any reader of the byte order `E`, with lengths stored as given by `L`
+pub trait HyperDecode<E, L: LengthPrefix = U32Prefix>: Sized {
+    /// Reads the value, advancing the reader past it</br>
+    /// Panics if there is not enough space, as with the plain `read_*` functions
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError>;
+}
+
+/// How the lengths of strings, `Vec`s, slices and maps are stored
+pub trait LengthPrefix {
+    /// Writes the length, erroring with [`ByteError::TooLong`] if it does not fit
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError>;
+
+    /// Reads the length, erroring with [`ByteError::OutOfRange`] if it does not fit a `usize`</br>
+    /// Panics if there is not enough space
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError>;
+}
+
+/// Lengths stored as a `u8`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct U8Prefix;
+
+/// Lengths stored as a `u16`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct U16Prefix;
+
+/// Lengths stored as a `u32`, which is the default
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct U32Prefix;
+
+/// Lengths stored as a `u64`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct U64Prefix;
+
+/// Lengths stored as an unsigned LEB128 variable-length integer, which ignores the byte order
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct VarintPrefix;
+
+fn checked_length<T: TryFrom<usize>>(length: usize, maximum: usize) -> Result<T, ByteError> {
+    T::try_from(length).map_err(|_| ByteError::TooLong { length, maximum })
+}
+
+fn to_usize<T: TryInto<usize>>(length: T) -> Result<usize, ByteError> {
+    length.try_into().map_err(|_| ByteError::OutOfRange)
+}
+
+impl LengthPrefix for U8Prefix {
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError> {
+        writer.write_u8(checked_length(length, u8::MAX as usize)?);
+        Ok(())
+    }
+
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError> {
+        Ok(reader.read_u8() as usize)
+    }
+}
+
+impl LengthPrefix for U16Prefix {
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError> {
+        writer.write_u16(checked_length(length, u16::MAX as usize)?);
+        Ok(())
+    }
+
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError> {
+        Ok(reader.read_u16() as usize)
+    }
+}
+
+impl LengthPrefix for U32Prefix {
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError> {
+        writer.write_u32(checked_length(length, u32::MAX as usize)?);
+        Ok(())
+    }
+
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError> {
+        to_usize(reader.read_u32())
+    }
+}
+
+impl LengthPrefix for U64Prefix {
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError> {
+        writer.write_u64(length as u64);
+        Ok(())
+    }
+
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError> {
+        to_usize(reader.read_u64())
+    }
+}
+
+impl LengthPrefix for VarintPrefix {
+    fn write_length<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, length: usize) -> Result<(), ByteError> {
+        writer.write_varint_u64(length as u64);
+        Ok(())
+    }
+
+    fn read_length<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<usize, ByteError> {
+        to_usize(reader.read_varint_u64()?)
+    }
+}
+
+/// Implements the codec traits for a primitive, from its `write_*` and `read_*` functions, where the read of a
+/// `fallible` one returns a [`Result`] of its own
+macro_rules! codec_primitive {
+    (@encode $ty:ty, $write:ident) => {
+        impl<E, L: LengthPrefix> HyperEncode<E, L> for $ty {
+            #[inline(always)]
+            fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+                writer.$write(*self);
+                Ok(())
+            }
+        }
+    };
+    (fallible $ty:ty, $write:ident, $read:ident) => {
+        codec_primitive!(@encode $ty, $write);
+
+        impl<E, L: LengthPrefix> HyperDecode<E, L> for $ty {
+            #[inline(always)]
+            fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+                reader.$read()
+            }
+        }
+    };
+    ($ty:ty, $write:ident, $read:ident) => {
+        codec_primitive!(@encode $ty, $write);
+
+        impl<E, L: LengthPrefix> HyperDecode<E, L> for $ty {
+            #[inline(always)]
+            fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+                Ok(reader.$read())
+            }
+        }
+    };
+}
+
+/// Implements the codec traits for a tuple, from the type parameter and index of each element
+macro_rules! codec_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<E, L: LengthPrefix, $($name: HyperEncode<E, L>),+> HyperEncode<E, L> for ($($name,)+) {
+            #[inline(always)]
+            fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+                $(self.$index.encode(writer)?;)+
+                Ok(())
+            }
+        }
+
+        impl<E, L: LengthPrefix, $($name: HyperDecode<E, L>),+> HyperDecode<E, L> for ($($name,)+) {
+            #[inline(always)]
+            fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+                Ok(($($name::decode(reader)?,)+))
+            }
+        }
+    };
+}
+
+codec_primitive!(u8, write_u8, read_u8);
+codec_primitive!(u16, write_u16, read_u16);
+codec_primitive!(u32, write_u32, read_u32);
+codec_primitive!(u64, write_u64, read_u64);
+codec_primitive!(u128, write_u128, read_u128);
+codec_primitive!(usize, write_usize, read_usize);
+codec_primitive!(i8, write_i8, read_i8);
+codec_primitive!(i16, write_i16, read_i16);
+codec_primitive!(i32, write_i32, read_i32);
+codec_primitive!(i64, write_i64, read_i64);
+codec_primitive!(i128, write_i128, read_i128);
+codec_primitive!(isize, write_isize, read_isize);
+#[cfg(feature = "half")]
+codec_primitive!(f16, write_f16, read_f16);
+#[cfg(feature = "half")]
+codec_primitive!(bf16, write_bf16, read_bf16);
+codec_primitive!(f32, write_f32, read_f32);
+codec_primitive!(f64, write_f64, read_f64);
+codec_primitive!(fallible bool, write_bool, read_bool);
+codec_primitive!(fallible char, write_char, read_char);
+
+impl<E, L: LengthPrefix> HyperEncode<E, L> for () {
+    #[inline(always)]
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, _: &mut W) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<E, L: LengthPrefix> HyperDecode<E, L> for () {
+    #[inline(always)]
+    fn decode<R: EndianReader<E> + ?Sized>(_: &mut R) -> Result<Self, ByteError> {
+        Ok(())
+    }
+}
+
+codec_tuple!(T0 0);
+codec_tuple!(T0 0, T1 1);
+codec_tuple!(T0 0, T1 1, T2 2);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+codec_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+
+/// Arrays have a length known up front, so no prefix is stored
+impl<E, L: LengthPrefix, T: HyperEncode<E, L>, const N: usize> HyperEncode<E, L> for [T; N] {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        for item in self {
+            item.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperDecode<E, L>, const N: usize> HyperDecode<E, L> for [T; N] {
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        try_from_fn(|| T::decode(reader))
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperEncode<E, L>> HyperEncode<E, L> for [T] {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        L::write_length(writer, self.len())?;
+        for item in self {
+            item.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperEncode<E, L>> HyperEncode<E, L> for Vec<T> {
+    #[inline(always)]
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        <[T] as HyperEncode<E, L>>::encode(self, writer)
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperDecode<E, L>> HyperDecode<E, L> for Vec<T> {
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        let length = L::read_length(reader)?;
+        // The length comes from the bytes, so it is not trusted with the capacity up front
+        (0..length).map(|_| T::decode(reader)).collect()
+    }
+}
+
+impl<E, L: LengthPrefix> HyperEncode<E, L> for str {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        L::write_length(writer, self.len())?;
+        writer.put_bytes(self.as_bytes());
+        Ok(())
+    }
+}
+
+impl<E, L: LengthPrefix> HyperEncode<E, L> for String {
+    #[inline(always)]
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        <str as HyperEncode<E, L>>::encode(self, writer)
+    }
+}
+
+/// Invalid UTF-8 results in an error, though the bytes are still consumed
+impl<E, L: LengthPrefix> HyperDecode<E, L> for String {
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        let length = L::read_length(reader)?;
+        read_string(reader, length)
+    }
+}
+
+/// Stored as a `bool` of whether the value is present, followed by the value
+impl<E, L: LengthPrefix, T: HyperEncode<E, L>> HyperEncode<E, L> for Option<T> {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        writer.write_bool(self.is_some());
+        match self {
+            Some(value) => value.encode(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperDecode<E, L>> HyperDecode<E, L> for Option<T> {
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        if reader.read_bool()? {
+            Ok(Some(T::decode(reader)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperEncode<E, L> + ?Sized> HyperEncode<E, L> for Box<T> {
+    #[inline(always)]
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        T::encode(self, writer)
+    }
+}
+
+impl<E, L: LengthPrefix, T: HyperDecode<E, L>> HyperDecode<E, L> for Box<T> {
+    #[inline(always)]
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        T::decode(reader).map(Box::new)
+    }
+}
+
+/// Stored as the amount of entries, followed by each key and its value in order
+impl<E, L: LengthPrefix, K: HyperEncode<E, L>, V: HyperEncode<E, L>> HyperEncode<E, L> for BTreeMap<K, V> {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        L::write_length(writer, self.len())?;
+        for (key, value) in self {
+            key.encode(writer)?;
+            value.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E, L: LengthPrefix, K: HyperDecode<E, L> + Ord, V: HyperDecode<E, L>> HyperDecode<E, L> for BTreeMap<K, V> {
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        let length = L::read_length(reader)?;
+        (0..length).map(|_| Ok((K::decode(reader)?, V::decode(reader)?))).collect()
+    }
+}
+
+/// Stored as the amount of entries, followed by each key and its value in the order of iteration
+#[cfg(feature = "std")]
+impl<E, L: LengthPrefix, K: HyperEncode<E, L>, V: HyperEncode<E, L>, S> HyperEncode<E, L> for HashMap<K, V, S> {
+    fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+        L::write_length(writer, self.len())?;
+        for (key, value) in self {
+            key.encode(writer)?;
+            value.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, L: LengthPrefix, K: HyperDecode<E, L> + Eq + Hash, V: HyperDecode<E, L>, S: BuildHasher + Default> HyperDecode<E, L>
+    for HashMap<K, V, S>
+{
+    fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+        let length = L::read_length(reader)?;
+        (0..length).map(|_| Ok((K::decode(reader)?, V::decode(reader)?))).collect()
+    }
+}
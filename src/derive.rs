@@ -0,0 +1,243 @@
+//! Traits for types which know how to read and write themselves, such as packet structs.<br/>
+//! With the `derive` feature, `#[derive(HyperRead, HyperWrite)]` implements them by reading and
+//! writing every field in order, so a struct no longer needs hand-written `from_be_bytes` and
+//! `to_be_bytes` functions.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::error::ByteError;
+use crate::readers::traits::ByteReader;
+use crate::writers::traits::ByteWriter;
+
+/// A type which can be read from the reader `R`
+pub trait HyperRead<R: ?Sized>: Sized {
+    /// Reads the value, advancing the reader past it</br>
+    /// Panics if there is not enough space, as with the plain `read_*` functions
+    fn hyper_read(reader: &mut R) -> Result<Self, ByteError>;
+}
+
+/// A type which can be written to the writer `W`
+pub trait HyperWrite<W: ?Sized> {
+    /// Writes the value to the end of the writer
+    fn hyper_write(&self, writer: &mut W) -> Result<(), ByteError>;
+}
+
+/// Derives [`HyperRead`] and [`HyperWrite`] for structs, tuple structs and enums.<br/>
+/// Primitive fields are read and written with the endian traits, in the endian given by
+/// `#[hyper(endian = "be")]`, `"le"` or `"ne"`, which is big-endian if left out. Any other field is
+/// read and written with its own [`HyperRead`] and [`HyperWrite`] implementation, other than a `String`,
+/// `Vec<T>`, `Option<T>` or `[T; N]`, which are stored the same way as with [`crate::codec`] so their elements
+/// follow the endian too.
+///
+/// Fields can also have attributes of their own:
+/// * `#[hyper(le)]`, `#[hyper(be)]` or `#[hyper(ne)]` overrides the endian for the field
+/// * `#[hyper(len = "count")]` reads a `Vec<T>` of `count` elements, or a `String` of `count` bytes, where
+///   `count` is any expression over earlier fields. Writing checks the length matches, but does not write it.
+///   Without it, the length is stored first as a `u32`
+/// * `#[hyper(magic = b"RIFF")]` on a `[u8; N]` field reads the bytes and errors if they differ, and always writes the constant
+/// * `#[hyper(pad = 3)]` skips 3 bytes before the field, and writes 3 zeroes
+/// * `#[hyper(skip)]` neither reads nor writes the field, filling it with [`Default::default`] instead
+/// * `#[hyper(if = "flags & 1 != 0")]` on an `Option<T>` field only reads it if the expression over earlier
+///   fields is true. Writing errors if the field is present when the expression is false, or the other way around
+///
+/// Enums are written as a tag followed by the fields of the variant:
+/// * `#[hyper(tag_type = "u16")]` on the enum stores the tag as a `"u8"`, `"u16"`, `"u32"`, `"u64"` or `"varint"`,
+///   which is a `u8` if left out. Fixed size tags use the endian of the enum
+/// * The tag of a variant is its discriminant, or `#[hyper(tag = 3)]` for variants with fields. Variants without
+///   either count up from the one before, like discriminants do
+/// * An unknown tag results in [`ByteError::UnknownTag`], unless a variant such as `Unknown(u16, Vec<u8>)` is marked
+///   `#[hyper(unknown)]`, which then holds the tag and every remaining byte. As the length of an unknown variant
+///   cannot be known, that includes any messages after it, so the enum is read from a reader over a single
+///   message, such as a frame sent with its length first
+/// # Examples
+/// ```
+/// use hyper_byte::derive::{HyperRead, HyperWrite};
+/// use hyper_byte::reader::FastByteReader;
+/// use hyper_byte::writer::FastByteWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+/// #[hyper(endian = "le")]
+/// struct Point(i32, i32);
+///
+/// #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+/// #[hyper(endian = "be")]
+/// struct Header {
+///     version: u16,
+///     compressed: bool,
+///     origin: Point,
+/// }
+///
+/// let header = Header { version: 3, compressed: true, origin: Point(-1, 2) };
+///
+/// let mut writer = FastByteWriter::new();
+/// header.hyper_write(&mut writer).unwrap();
+///
+/// let buffer = writer.to_vec();
+/// assert_eq!(buffer, [0, 3, 1, 0xFF, 0xFF, 0xFF, 0xFF, 2, 0, 0, 0]);
+///
+/// let mut reader = FastByteReader::new(&buffer);
+/// assert_eq!(Header::hyper_read(&mut reader).unwrap(), header);
+/// ```
+///
+/// With the field attributes:
+/// ```
+/// use hyper_byte::derive::{HyperRead, HyperWrite};
+/// use hyper_byte::reader::FastByteReader;
+/// use hyper_byte::writer::FastByteWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+/// #[hyper(endian = "le")]
+/// struct Chunk {
+///     #[hyper(magic = b"RIFF")]
+///     magic: [u8; 4],
+///     #[hyper(be)]
+///     count: u16,
+///     flags: u8,
+///     #[hyper(pad = 1, len = "count")]
+///     samples: Vec<i16>,
+///     #[hyper(if = "flags & 1 != 0")]
+///     checksum: Option<u32>,
+///     #[hyper(skip)]
+///     cached: bool,
+/// }
+///
+/// let chunk = Chunk { magic: *b"RIFF", count: 2, flags: 1, samples: vec![-1, 2], checksum: Some(7), cached: false };
+///
+/// let mut writer = FastByteWriter::new();
+/// chunk.hyper_write(&mut writer).unwrap();
+///
+/// let buffer = writer.to_vec();
+/// assert_eq!(buffer, *b"RIFF\x00\x02\x01\x00\xFF\xFF\x02\x00\x07\x00\x00\x00");
+///
+/// let mut reader = FastByteReader::new(&buffer);
+/// assert_eq!(Chunk::hyper_read(&mut reader).unwrap(), chunk);
+/// ```
+///
+/// With an enum:
+/// ```
+/// use hyper_byte::derive::{HyperRead, HyperWrite};
+/// use hyper_byte::reader::FastByteReader;
+/// use hyper_byte::writer::FastByteWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+/// #[hyper(endian = "be", tag_type = "u16")]
+/// enum Message {
+///     Ping,
+///     #[hyper(tag = 0x10)]
+///     Move { x: i32, y: i32 },
+///     #[hyper(unknown)]
+///     Unknown(u16, Vec<u8>),
+/// }
+///
+/// let mut writer = FastByteWriter::new();
+/// Message::Move { x: 1, y: -1 }.hyper_write(&mut writer).unwrap();
+/// Message::Ping.hyper_write(&mut writer).unwrap();
+///
+/// let buffer = writer.to_vec();
+/// assert_eq!(buffer, [0, 0x10, 0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0, 0]);
+///
+/// let mut reader = FastByteReader::new(&buffer);
+/// assert_eq!(Message::hyper_read(&mut reader).unwrap(), Message::Move { x: 1, y: -1 });
+/// assert_eq!(Message::hyper_read(&mut reader).unwrap(), Message::Ping);
+///
+/// let mut reader = FastByteReader::new(&[0, 0x20, 1, 2]);
+/// assert_eq!(Message::hyper_read(&mut reader).unwrap(), Message::Unknown(0x20, vec![1, 2]));
+/// ```
+#[cfg(feature = "derive")]
+pub use hyper_byte_derive::{HyperRead, HyperWrite};
+
+/// Converts the value of a `len` attribute into a length, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn length<T: TryInto<usize>>(value: T) -> Result<usize, ByteError> {
+    value.try_into().map_err(|_| ByteError::OutOfRange)
+}
+
+/// Checks a value has the length given by its `len` attribute, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn check_length(expected: usize, actual: usize) -> Result<(), ByteError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ByteError::LengthMismatch { expected, actual })
+    }
+}
+
+/// Reads `length` bytes of UTF-8, used by the derived implementations.<br/>
+/// Invalid UTF-8 results in an error, though the bytes are still consumed
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_string<R: ByteReader + ?Sized>(reader: &mut R, length: usize) -> Result<String, ByteError> {
+    crate::text::read_string(reader, length)
+}
+
+/// Reads `length` elements with `read`, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_vec<R: ?Sized, T>(
+    reader: &mut R,
+    length: usize,
+    mut read: impl FnMut(&mut R) -> Result<T, ByteError>,
+) -> Result<Vec<T>, ByteError> {
+    // The length comes from the bytes, so it is not trusted with the capacity up front
+    (0..length).map(|_| read(reader)).collect()
+}
+
+/// Reads the bytes of a `magic` attribute, used by the derived implementations.<br/>
+/// Bytes which do not match result in an error, though they are still consumed
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_magic<R: ByteReader + ?Sized>(reader: &mut R, magic: &[u8]) -> Result<(), ByteError> {
+    let byte_array = reader.byte_array();
+    if byte_array.len() < magic.len() {
+        panic!("Attempted to read magic bytes of an array without enough space within the array.");
+    }
+    let new_length = byte_array.len() - magic.len();
+    let matches = &byte_array[..magic.len()] == magic;
+    // SAFETY: The length was checked against the array above
+    unsafe {
+        reader.advance(magic.len(), new_length);
+    }
+    if matches { Ok(()) } else { Err(ByteError::InvalidMagic) }
+}
+
+/// Reads `N` elements with `read`, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_array<R: ?Sized, T, const N: usize>(
+    reader: &mut R,
+    mut read: impl FnMut(&mut R) -> Result<T, ByteError>,
+) -> Result<[T; N], ByteError> {
+    crate::array::try_from_fn(|| read(reader))
+}
+
+/// Converts the length of a `String` or `Vec` into its `u32` prefix, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn prefix_length(length: usize) -> Result<u32, ByteError> {
+    u32::try_from(length).map_err(|_| ByteError::TooLong { length, maximum: u32::MAX as usize })
+}
+
+/// Writes `amount` zeroes for a `pad` attribute, used by the derived implementations
+#[doc(hidden)]
+#[inline(always)]
+pub fn write_padding<W: ByteWriter + ?Sized>(writer: &mut W, amount: usize) {
+    writer.put_zeros(amount);
+}
+
+/// Reads every remaining byte for an `unknown` variant, used by the derived implementations.<br/>
+/// This includes the bytes of any messages after it, as the length of the variant is not stored
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_remaining<R: ByteReader + ?Sized>(reader: &mut R) -> Vec<u8> {
+    let bytes = reader.byte_array().to_vec();
+    // SAFETY: Advancing by the full length leaves nothing behind
+    unsafe {
+        reader.advance(bytes.len(), 0);
+    }
+    bytes
+}
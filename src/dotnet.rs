@@ -0,0 +1,331 @@
+//! Compatibility with .NET's `System.IO.BinaryReader` and `System.IO.BinaryWriter`.<br/>
+//! The numeric types are plain little-endian, so they are read with [`LittleEndianByteReader`] and
+//! written with [`LittleEndianByteWriter`]. This adds the encodings which differ: 7-bit encoded
+//! integers, length-prefixed UTF-8 strings, `char` as UTF-8 and `decimal`.
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::dotnet::{DotNetBinaryReader, DotNetBinaryWriter};
+//! use hyper_byte::reader::FastByteReader;
+//! use hyper_byte::readers::traits::LittleEndianByteReader;
+//! use hyper_byte::writer::FastByteWriter;
+//! use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+//!
+//! let mut writer = FastByteWriter::new();
+//! writer.write_i32_le(42);
+//! writer.write_dotnet_string("hyper byte").unwrap();
+//! writer.write_7bit_encoded_int(300);
+//!
+//! let buffer = writer.to_vec();
+//! let mut reader = FastByteReader::new(&buffer);
+//!
+//! assert_eq!(reader.read_i32_le(), 42);
+//! assert_eq!(reader.read_dotnet_string().unwrap(), "hyper byte");
+//! assert_eq!(reader.read_7bit_encoded_int().unwrap(), 300);
+//! ```
+
+use alloc::string::String;
+use core::str::from_utf8;
+use crate::error::ByteError;
+use crate::readers::traits::LittleEndianByteReader;
+use crate::writers::traits::LittleEndianByteWriter;
+
+/// The largest mantissa a `decimal` can hold, which is 96 bits
+const DECIMAL_MANTISSA_MAX: u128 = (1 << 96) - 1;
+/// The largest scale a `decimal` can have
+const DECIMAL_SCALE_MAX: u8 = 28;
+const DECIMAL_SIGN_MASK: u32 = 0x8000_0000;
+const DECIMAL_SCALE_SHIFT: u32 = 16;
+
+/// A .NET `decimal`, which is `mantissa / 10^scale`, negated if `negative` is set
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// The 96-bit unsigned integer the value is made of
+    pub mantissa: u128,
+    /// The power of ten the mantissa is divided by, from `0` to `28`
+    pub scale: u8,
+    pub negative: bool,
+}
+
+pub trait DotNetBinaryReader: LittleEndianByteReader {
+    /// Reads an `int` written by `Write7BitEncodedInt`, advancing the readers forward by 1 to 5 bytes</br>
+    /// More than 32 bits of value results in an error, as with `Read7BitEncodedInt`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_7bit_encoded_int(&mut self) -> Result<i32, ByteError> {
+        let mut value = 0u32;
+        for shift in (0..28).step_by(7) {
+            let byte = self.read_u8_le();
+            value |= ((byte & 0x7F) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value as i32);
+            }
+        }
+        // The fifth byte only has room for the top 4 bits
+        let byte = self.read_u8_le();
+        if byte > 0x0F {
+            return Err(ByteError::OutOfRange);
+        }
+        Ok((value | (byte as u32) << 28) as i32)
+    }
+
+    /// Reads a `long` written by `Write7BitEncodedInt64`, advancing the readers forward by 1 to 10 bytes</br>
+    /// More than 64 bits of value results in an error, as with `Read7BitEncodedInt64`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_7bit_encoded_int64(&mut self) -> Result<i64, ByteError> {
+        let mut value = 0u64;
+        for shift in (0..63).step_by(7) {
+            let byte = self.read_u8_le();
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value as i64);
+            }
+        }
+        // The tenth byte only has room for the top bit
+        let byte = self.read_u8_le();
+        if byte > 0x01 {
+            return Err(ByteError::OutOfRange);
+        }
+        Ok((value | (byte as u64) << 63) as i64)
+    }
+
+    /// Reads a string written by `BinaryWriter.Write(string)`, advancing the readers past the prefix and the string</br>
+    /// The string is UTF-8, prefixed by its length in bytes as a 7-bit encoded `int`</br>
+    /// A negative length or invalid UTF-8 results in an error, though the string is still consumed if the length was valid</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_dotnet_string(&mut self) -> Result<String, ByteError> {
+        let length = usize::try_from(self.read_7bit_encoded_int()?).map_err(|_| ByteError::OutOfRange)?;
+        let byte_array = self.byte_array();
+        if byte_array.len() < length {
+            panic!("Attempted to read a string of an array without enough space within the array.");
+        }
+        let new_length = byte_array.len() - length;
+        let string = from_utf8(&byte_array[..length])
+            .map(String::from)
+            .map_err(|error| ByteError::InvalidUtf8 { position: error.valid_up_to() });
+        // SAFETY: The length was checked against the array above
+        unsafe {
+            self.advance(length, new_length);
+        }
+        string
+    }
+
+    /// Reads a `char` written by `BinaryWriter.Write(char)`, advancing the readers forward by 1 to 3 bytes</br>
+    /// A .NET `char` is a single UTF-16 code unit, so anything but a 1 to 3 byte UTF-8 sequence results in an error,
+    /// though the bytes of a complete sequence are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_dotnet_char(&mut self) -> Result<char, ByteError> {
+        let byte_array = self.byte_array();
+        let length = match byte_array.first() {
+            Some(0x00..=0x7F) => 1,
+            Some(0xC0..=0xDF) => 2,
+            Some(0xE0..=0xEF) => 3,
+            Some(_) => return Err(ByteError::InvalidUtf8 { position: 0 }),
+            None => panic!("Attempted to read a char of an array without enough space within the array."),
+        };
+        if byte_array.len() < length {
+            panic!("Attempted to read a char of an array without enough space within the array.");
+        }
+        let new_length = byte_array.len() - length;
+        let character = from_utf8(&byte_array[..length])
+            .map(|string| string.chars().next().unwrap_or_default())
+            .map_err(|error| ByteError::InvalidUtf8 { position: error.valid_up_to() });
+        // SAFETY: The length was checked against the array above
+        unsafe {
+            self.advance(length, new_length);
+        }
+        character
+    }
+
+    /// Reads a `decimal` written by `BinaryWriter.Write(decimal)`, advancing the readers forward by 16 bytes</br>
+    /// A scale above `28` or any of the reserved bits being set results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_dotnet_decimal(&mut self) -> Result<Decimal, ByteError> {
+        let low = self.read_u32_le() as u128;
+        let middle = self.read_u32_le() as u128;
+        let high = self.read_u32_le() as u128;
+        let flags = self.read_u32_le();
+        let scale = (flags >> DECIMAL_SCALE_SHIFT) as u8;
+        if flags & !(DECIMAL_SIGN_MASK | 0xFF << DECIMAL_SCALE_SHIFT) != 0 || scale > DECIMAL_SCALE_MAX {
+            return Err(ByteError::OutOfRange);
+        }
+        Ok(Decimal {
+            mantissa: high << 64 | middle << 32 | low,
+            scale,
+            negative: flags & DECIMAL_SIGN_MASK != 0,
+        })
+    }
+}
+
+impl<R: LittleEndianByteReader + ?Sized> DotNetBinaryReader for R {}
+
+pub trait DotNetBinaryWriter: LittleEndianByteWriter {
+    /// For writing an `i32` the way `Write7BitEncodedInt` does, 7 bits at a time with the top bit set while more follow<br/>
+    /// Negative values are written as their unsigned bits, which always takes 5 bytes
+    /// # Examples
+    /// ```
+    /// use hyper_byte::dotnet::{DotNetBinaryReader, DotNetBinaryWriter};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (127i32, -1i32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_7bit_encoded_int(my_simple_tuple.0);
+    /// writer.write_7bit_encoded_int(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_7bit_encoded_int().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_7bit_encoded_int().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_7bit_encoded_int(&mut self, value: i32) {
+        let mut value = value as u32;
+        while value > 0x7F {
+            self.write_u8_le(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.write_u8_le(value as u8);
+    }
+
+    /// For writing an `i64` the way `Write7BitEncodedInt64` does, 7 bits at a time with the top bit set while more follow<br/>
+    /// Negative values are written as their unsigned bits, which always takes 10 bytes
+    /// # Examples
+    /// ```
+    /// use hyper_byte::dotnet::{DotNetBinaryReader, DotNetBinaryWriter};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (1i64 << 40, i64::MIN);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_7bit_encoded_int64(my_simple_tuple.0);
+    /// writer.write_7bit_encoded_int64(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_7bit_encoded_int64().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_7bit_encoded_int64().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_7bit_encoded_int64(&mut self, value: i64) {
+        let mut value = value as u64;
+        while value > 0x7F {
+            self.write_u8_le(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.write_u8_le(value as u8);
+    }
+
+    /// For writing a string the way `BinaryWriter.Write(string)` does, as UTF-8 prefixed by its length in bytes as a 7-bit encoded `int`<br/>
+    /// Returns an error without writing anything if the string is longer than [`i32::MAX`] bytes
+    /// # Examples
+    /// ```
+    /// use hyper_byte::dotnet::{DotNetBinaryReader, DotNetBinaryWriter};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_dotnet_string(my_simple_tuple.0).unwrap();
+    /// writer.write_dotnet_string(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_dotnet_string().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_dotnet_string().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_dotnet_string(&mut self, value: &str) -> Result<(), ByteError> {
+        let length = i32::try_from(value.len()).map_err(|_| ByteError::TooLong {
+            length: value.len(),
+            maximum: i32::MAX as usize,
+        })?;
+        self.write_7bit_encoded_int(length);
+        self.put_bytes(value.as_bytes());
+        Ok(())
+    }
+
+    /// For writing a `char` the way `BinaryWriter.Write(char)` does, as UTF-8<br/>
+    /// Returns an error for a `char` outside of the Basic Multilingual Plane, as a .NET `char` cannot hold it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::dotnet::{DotNetBinaryReader, DotNetBinaryWriter};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = ('h', '€');
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_dotnet_char(my_simple_tuple.0).unwrap();
+    /// writer.write_dotnet_char(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_dotnet_char().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_dotnet_char().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_dotnet_char(&mut self, value: char) -> Result<(), ByteError> {
+        if value.len_utf16() != 1 {
+            return Err(ByteError::OutOfRange);
+        }
+        let mut bytes = [0; 4];
+        self.put_bytes(value.encode_utf8(&mut bytes).as_bytes());
+        Ok(())
+    }
+
+    /// For writing a [`Decimal`] the way `BinaryWriter.Write(decimal)` does, as the low, middle and high 32 bits of
+    /// the mantissa followed by the flags, all in little-endian order<br/>
+    /// Returns an error if the mantissa does not fit in 96 bits or the scale is above `28`
+    /// # Examples
+    /// ```
+    /// use hyper_byte::dotnet::{Decimal, DotNetBinaryReader, DotNetBinaryWriter};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (
+    ///     Decimal { mantissa: 12345, scale: 2, negative: false },
+    ///     Decimal { mantissa: (1 << 96) - 1, scale: 28, negative: true },
+    /// );
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_dotnet_decimal(my_simple_tuple.0).unwrap();
+    /// writer.write_dotnet_decimal(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_dotnet_decimal().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_dotnet_decimal().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_dotnet_decimal(&mut self, value: Decimal) -> Result<(), ByteError> {
+        if value.mantissa > DECIMAL_MANTISSA_MAX || value.scale > DECIMAL_SCALE_MAX {
+            return Err(ByteError::OutOfRange);
+        }
+        let sign = if value.negative { DECIMAL_SIGN_MASK } else { 0 };
+        self.write_u32_le(value.mantissa as u32);
+        self.write_u32_le((value.mantissa >> 32) as u32);
+        self.write_u32_le((value.mantissa >> 64) as u32);
+        self.write_u32_le(sign | (value.scale as u32) << DECIMAL_SCALE_SHIFT);
+        Ok(())
+    }
+}
+
+impl<W: LittleEndianByteWriter + ?Sized> DotNetBinaryWriter for W {}
@@ -0,0 +1,732 @@
+//! Marker types for the byte orders, so code can be written once over any of them.<br/>
+//! [`EndianReader`] and [`EndianWriter`] are implemented for every reader and writer of the matching
+//! endian trait, forwarding to its `read_*` and `write_*` functions, such as `read_u32` to `read_u32_be`
+//! for [`BigEndian`].
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::endian::{BigEndian, EndianReader, EndianWriter, LittleEndian};
+//! use hyper_byte::reader::FastByteReader;
+//! use hyper_byte::writer::FastByteWriter;
+//! use hyper_byte::writers::traits::ByteWriter;
+//!
+//! fn write_point<E, W: EndianWriter<E>>(writer: &mut W, x: i32, y: i32) {
+//!     writer.write_i32(x);
+//!     writer.write_i32(y);
+//! }
+//!
+//! let mut writer = FastByteWriter::new();
+//! write_point::<BigEndian, _>(&mut writer, 1, -1);
+//! write_point::<LittleEndian, _>(&mut writer, 1, -1);
+//!
+//! let buffer = writer.to_vec();
+//! let mut reader = FastByteReader::new(&buffer);
+//! assert_eq!(EndianReader::<BigEndian>::read_i32(&mut reader), 1);
+//! assert_eq!(EndianReader::<BigEndian>::read_i32(&mut reader), -1);
+//! assert_eq!(EndianReader::<LittleEndian>::read_i32(&mut reader), 1);
+//! assert_eq!(EndianReader::<LittleEndian>::read_i32(&mut reader), -1);
+//! ```
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use crate::codec::{HyperDecode, HyperEncode, LengthPrefix};
+use crate::error::ByteError;
+use crate::readers::traits::{BigEndianByteReader, ByteReader, LittleEndianByteReader, NativeEndianByteReader};
+use crate::writers::traits::{BigEndianByteWriter, ByteWriter, LittleEndianByteWriter, NativeEndianByteWriter};
+
+/// Big-endian, or network order, as read and written by [`BigEndianByteReader`] and [`BigEndianByteWriter`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BigEndian;
+
+/// Little-endian, as read and written by [`LittleEndianByteReader`] and [`LittleEndianByteWriter`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct LittleEndian;
+
+/// The endian of the target, as read and written by [`NativeEndianByteReader`] and [`NativeEndianByteWriter`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct NativeEndian;
+
+/// A reader of the byte order `E`, being one of [`BigEndian`], [`LittleEndian`] or [`NativeEndian`]
+pub trait EndianReader<E>: ByteReader {
+    /// Reads a [`u8`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_u8(&mut self) -> u8;
+
+    /// Reads a [`u16`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_u16(&mut self) -> u16;
+
+    /// Reads a [`u32`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_u32(&mut self) -> u32;
+
+    /// Reads a [`u64`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_u64(&mut self) -> u64;
+
+    /// Reads a [`u128`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_u128(&mut self) -> u128;
+
+    /// Reads a [`usize`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_usize(&mut self) -> usize;
+
+    /// Reads an [`i8`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_i8(&mut self) -> i8;
+
+    /// Reads an [`i16`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_i16(&mut self) -> i16;
+
+    /// Reads an [`i32`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_i32(&mut self) -> i32;
+
+    /// Reads an [`i64`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_i64(&mut self) -> i64;
+
+    /// Reads an [`i128`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_i128(&mut self) -> i128;
+
+    /// Reads an [`isize`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_isize(&mut self) -> isize;
+
+    #[cfg(feature = "half")]
+    /// Reads a [`f16`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_f16(&mut self) -> f16;
+
+    #[cfg(feature = "half")]
+    /// Reads a [`bf16`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_bf16(&mut self) -> bf16;
+
+    /// Reads a [`f32`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_f32(&mut self) -> f32;
+
+    /// Reads a [`f64`] in the byte order `E`</br>
+    /// Panics if there is not enough space
+    fn read_f64(&mut self) -> f64;
+
+    /// Reads a [`char`] in the byte order `E`, stored as a `u32`</br>
+    /// Panics if there is not enough space
+    fn read_char(&mut self) -> Result<char, ByteError>;
+
+    /// Decodes a [`HyperDecode`] value in the byte order `E`, with `u32` length prefixes</br>
+    /// Panics if there is not enough space
+    fn decode<T: HyperDecode<E>>(&mut self) -> Result<T, ByteError> {
+        T::decode(self)
+    }
+
+    /// Decodes a [`HyperDecode`] value in the byte order `E`, with length prefixes stored as given by `L`</br>
+    /// Panics if there is not enough space
+    fn decode_with<T: HyperDecode<E, L>, L: LengthPrefix>(&mut self) -> Result<T, ByteError> {
+        T::decode(self)
+    }
+}
+
+/// A writer of the byte order `E`, being one of [`BigEndian`], [`LittleEndian`] or [`NativeEndian`]
+pub trait EndianWriter<E>: ByteWriter {
+    /// Writes a [`u8`] in the byte order `E`
+    fn write_u8(&mut self, value: u8);
+
+    /// Writes a [`u16`] in the byte order `E`
+    fn write_u16(&mut self, value: u16);
+
+    /// Writes a [`u32`] in the byte order `E`
+    fn write_u32(&mut self, value: u32);
+
+    /// Writes a [`u64`] in the byte order `E`
+    fn write_u64(&mut self, value: u64);
+
+    /// Writes a [`u128`] in the byte order `E`
+    fn write_u128(&mut self, value: u128);
+
+    /// Writes a [`usize`] in the byte order `E`
+    fn write_usize(&mut self, value: usize);
+
+    /// Writes an [`i8`] in the byte order `E`
+    fn write_i8(&mut self, value: i8);
+
+    /// Writes an [`i16`] in the byte order `E`
+    fn write_i16(&mut self, value: i16);
+
+    /// Writes an [`i32`] in the byte order `E`
+    fn write_i32(&mut self, value: i32);
+
+    /// Writes an [`i64`] in the byte order `E`
+    fn write_i64(&mut self, value: i64);
+
+    /// Writes an [`i128`] in the byte order `E`
+    fn write_i128(&mut self, value: i128);
+
+    /// Writes an [`isize`] in the byte order `E`
+    fn write_isize(&mut self, value: isize);
+
+    #[cfg(feature = "half")]
+    /// Writes a [`f16`] in the byte order `E`
+    fn write_f16(&mut self, value: f16);
+
+    #[cfg(feature = "half")]
+    /// Writes a [`bf16`] in the byte order `E`
+    fn write_bf16(&mut self, value: bf16);
+
+    /// Writes a [`f32`] in the byte order `E`
+    fn write_f32(&mut self, value: f32);
+
+    /// Writes a [`f64`] in the byte order `E`
+    fn write_f64(&mut self, value: f64);
+
+    /// Writes a [`char`] in the byte order `E`, stored as a `u32`
+    fn write_char(&mut self, value: char);
+
+    /// Encodes a [`HyperEncode`] value in the byte order `E`, with `u32` length prefixes
+    fn encode<T: HyperEncode<E> + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.encode(self)
+    }
+
+    /// Encodes a [`HyperEncode`] value in the byte order `E`, with length prefixes stored as given by `L`
+    fn encode_with<T: HyperEncode<E, L> + ?Sized, L: LengthPrefix>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.encode(self)
+    }
+}
+
+impl<R: BigEndianByteReader + ?Sized> EndianReader<BigEndian> for R {
+    #[inline(always)]
+    fn read_u8(&mut self) -> u8 {
+        self.read_u8_be()
+    }
+
+    #[inline(always)]
+    fn read_u16(&mut self) -> u16 {
+        self.read_u16_be()
+    }
+
+    #[inline(always)]
+    fn read_u32(&mut self) -> u32 {
+        self.read_u32_be()
+    }
+
+    #[inline(always)]
+    fn read_u64(&mut self) -> u64 {
+        self.read_u64_be()
+    }
+
+    #[inline(always)]
+    fn read_u128(&mut self) -> u128 {
+        self.read_u128_be()
+    }
+
+    #[inline(always)]
+    fn read_usize(&mut self) -> usize {
+        self.read_usize_be()
+    }
+
+    #[inline(always)]
+    fn read_i8(&mut self) -> i8 {
+        self.read_i8_be()
+    }
+
+    #[inline(always)]
+    fn read_i16(&mut self) -> i16 {
+        self.read_i16_be()
+    }
+
+    #[inline(always)]
+    fn read_i32(&mut self) -> i32 {
+        self.read_i32_be()
+    }
+
+    #[inline(always)]
+    fn read_i64(&mut self) -> i64 {
+        self.read_i64_be()
+    }
+
+    #[inline(always)]
+    fn read_i128(&mut self) -> i128 {
+        self.read_i128_be()
+    }
+
+    #[inline(always)]
+    fn read_isize(&mut self) -> isize {
+        self.read_isize_be()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_f16(&mut self) -> f16 {
+        self.read_f16_be()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_bf16(&mut self) -> bf16 {
+        self.read_bf16_be()
+    }
+
+    #[inline(always)]
+    fn read_f32(&mut self) -> f32 {
+        self.read_f32_be()
+    }
+
+    #[inline(always)]
+    fn read_f64(&mut self) -> f64 {
+        self.read_f64_be()
+    }
+
+    #[inline(always)]
+    fn read_char(&mut self) -> Result<char, ByteError> {
+        self.read_char_be()
+    }
+}
+
+impl<W: BigEndianByteWriter + ?Sized> EndianWriter<BigEndian> for W {
+    #[inline(always)]
+    fn write_u8(&mut self, value: u8) {
+        self.write_u8_be(value);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, value: u16) {
+        self.write_u16_be(value);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, value: u32) {
+        self.write_u32_be(value);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, value: u64) {
+        self.write_u64_be(value);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, value: u128) {
+        self.write_u128_be(value);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, value: usize) {
+        self.write_usize_be(value);
+    }
+
+    #[inline(always)]
+    fn write_i8(&mut self, value: i8) {
+        self.write_i8_be(value);
+    }
+
+    #[inline(always)]
+    fn write_i16(&mut self, value: i16) {
+        self.write_i16_be(value);
+    }
+
+    #[inline(always)]
+    fn write_i32(&mut self, value: i32) {
+        self.write_i32_be(value);
+    }
+
+    #[inline(always)]
+    fn write_i64(&mut self, value: i64) {
+        self.write_i64_be(value);
+    }
+
+    #[inline(always)]
+    fn write_i128(&mut self, value: i128) {
+        self.write_i128_be(value);
+    }
+
+    #[inline(always)]
+    fn write_isize(&mut self, value: isize) {
+        self.write_isize_be(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_f16(&mut self, value: f16) {
+        self.write_f16_be(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_bf16(&mut self, value: bf16) {
+        self.write_bf16_be(value);
+    }
+
+    #[inline(always)]
+    fn write_f32(&mut self, value: f32) {
+        self.write_f32_be(value);
+    }
+
+    #[inline(always)]
+    fn write_f64(&mut self, value: f64) {
+        self.write_f64_be(value);
+    }
+
+    #[inline(always)]
+    fn write_char(&mut self, value: char) {
+        self.write_char_be(value);
+    }
+}
+
+impl<R: LittleEndianByteReader + ?Sized> EndianReader<LittleEndian> for R {
+    #[inline(always)]
+    fn read_u8(&mut self) -> u8 {
+        self.read_u8_le()
+    }
+
+    #[inline(always)]
+    fn read_u16(&mut self) -> u16 {
+        self.read_u16_le()
+    }
+
+    #[inline(always)]
+    fn read_u32(&mut self) -> u32 {
+        self.read_u32_le()
+    }
+
+    #[inline(always)]
+    fn read_u64(&mut self) -> u64 {
+        self.read_u64_le()
+    }
+
+    #[inline(always)]
+    fn read_u128(&mut self) -> u128 {
+        self.read_u128_le()
+    }
+
+    #[inline(always)]
+    fn read_usize(&mut self) -> usize {
+        self.read_usize_le()
+    }
+
+    #[inline(always)]
+    fn read_i8(&mut self) -> i8 {
+        self.read_i8_le()
+    }
+
+    #[inline(always)]
+    fn read_i16(&mut self) -> i16 {
+        self.read_i16_le()
+    }
+
+    #[inline(always)]
+    fn read_i32(&mut self) -> i32 {
+        self.read_i32_le()
+    }
+
+    #[inline(always)]
+    fn read_i64(&mut self) -> i64 {
+        self.read_i64_le()
+    }
+
+    #[inline(always)]
+    fn read_i128(&mut self) -> i128 {
+        self.read_i128_le()
+    }
+
+    #[inline(always)]
+    fn read_isize(&mut self) -> isize {
+        self.read_isize_le()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_f16(&mut self) -> f16 {
+        self.read_f16_le()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_bf16(&mut self) -> bf16 {
+        self.read_bf16_le()
+    }
+
+    #[inline(always)]
+    fn read_f32(&mut self) -> f32 {
+        self.read_f32_le()
+    }
+
+    #[inline(always)]
+    fn read_f64(&mut self) -> f64 {
+        self.read_f64_le()
+    }
+
+    #[inline(always)]
+    fn read_char(&mut self) -> Result<char, ByteError> {
+        self.read_char_le()
+    }
+}
+
+impl<W: LittleEndianByteWriter + ?Sized> EndianWriter<LittleEndian> for W {
+    #[inline(always)]
+    fn write_u8(&mut self, value: u8) {
+        self.write_u8_le(value);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, value: u16) {
+        self.write_u16_le(value);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, value: u32) {
+        self.write_u32_le(value);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, value: u64) {
+        self.write_u64_le(value);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, value: u128) {
+        self.write_u128_le(value);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, value: usize) {
+        self.write_usize_le(value);
+    }
+
+    #[inline(always)]
+    fn write_i8(&mut self, value: i8) {
+        self.write_i8_le(value);
+    }
+
+    #[inline(always)]
+    fn write_i16(&mut self, value: i16) {
+        self.write_i16_le(value);
+    }
+
+    #[inline(always)]
+    fn write_i32(&mut self, value: i32) {
+        self.write_i32_le(value);
+    }
+
+    #[inline(always)]
+    fn write_i64(&mut self, value: i64) {
+        self.write_i64_le(value);
+    }
+
+    #[inline(always)]
+    fn write_i128(&mut self, value: i128) {
+        self.write_i128_le(value);
+    }
+
+    #[inline(always)]
+    fn write_isize(&mut self, value: isize) {
+        self.write_isize_le(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_f16(&mut self, value: f16) {
+        self.write_f16_le(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_bf16(&mut self, value: bf16) {
+        self.write_bf16_le(value);
+    }
+
+    #[inline(always)]
+    fn write_f32(&mut self, value: f32) {
+        self.write_f32_le(value);
+    }
+
+    #[inline(always)]
+    fn write_f64(&mut self, value: f64) {
+        self.write_f64_le(value);
+    }
+
+    #[inline(always)]
+    fn write_char(&mut self, value: char) {
+        self.write_char_le(value);
+    }
+}
+
+impl<R: NativeEndianByteReader + ?Sized> EndianReader<NativeEndian> for R {
+    #[inline(always)]
+    fn read_u8(&mut self) -> u8 {
+        self.read_u8_ne()
+    }
+
+    #[inline(always)]
+    fn read_u16(&mut self) -> u16 {
+        self.read_u16_ne()
+    }
+
+    #[inline(always)]
+    fn read_u32(&mut self) -> u32 {
+        self.read_u32_ne()
+    }
+
+    #[inline(always)]
+    fn read_u64(&mut self) -> u64 {
+        self.read_u64_ne()
+    }
+
+    #[inline(always)]
+    fn read_u128(&mut self) -> u128 {
+        self.read_u128_ne()
+    }
+
+    #[inline(always)]
+    fn read_usize(&mut self) -> usize {
+        self.read_usize_ne()
+    }
+
+    #[inline(always)]
+    fn read_i8(&mut self) -> i8 {
+        self.read_i8_ne()
+    }
+
+    #[inline(always)]
+    fn read_i16(&mut self) -> i16 {
+        self.read_i16_ne()
+    }
+
+    #[inline(always)]
+    fn read_i32(&mut self) -> i32 {
+        self.read_i32_ne()
+    }
+
+    #[inline(always)]
+    fn read_i64(&mut self) -> i64 {
+        self.read_i64_ne()
+    }
+
+    #[inline(always)]
+    fn read_i128(&mut self) -> i128 {
+        self.read_i128_ne()
+    }
+
+    #[inline(always)]
+    fn read_isize(&mut self) -> isize {
+        self.read_isize_ne()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_f16(&mut self) -> f16 {
+        self.read_f16_ne()
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn read_bf16(&mut self) -> bf16 {
+        self.read_bf16_ne()
+    }
+
+    #[inline(always)]
+    fn read_f32(&mut self) -> f32 {
+        self.read_f32_ne()
+    }
+
+    #[inline(always)]
+    fn read_f64(&mut self) -> f64 {
+        self.read_f64_ne()
+    }
+
+    #[inline(always)]
+    fn read_char(&mut self) -> Result<char, ByteError> {
+        self.read_char_ne()
+    }
+}
+
+impl<W: NativeEndianByteWriter + ?Sized> EndianWriter<NativeEndian> for W {
+    #[inline(always)]
+    fn write_u8(&mut self, value: u8) {
+        self.write_u8_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, value: u16) {
+        self.write_u16_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, value: u32) {
+        self.write_u32_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, value: u64) {
+        self.write_u64_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, value: u128) {
+        self.write_u128_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, value: usize) {
+        self.write_usize_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_i8(&mut self, value: i8) {
+        self.write_i8_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_i16(&mut self, value: i16) {
+        self.write_i16_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_i32(&mut self, value: i32) {
+        self.write_i32_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_i64(&mut self, value: i64) {
+        self.write_i64_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_i128(&mut self, value: i128) {
+        self.write_i128_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_isize(&mut self, value: isize) {
+        self.write_isize_ne(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_f16(&mut self, value: f16) {
+        self.write_f16_ne(value);
+    }
+
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    fn write_bf16(&mut self, value: bf16) {
+        self.write_bf16_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_f32(&mut self, value: f32) {
+        self.write_f32_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_f64(&mut self, value: f64) {
+        self.write_f64_ne(value);
+    }
+
+    #[inline(always)]
+    fn write_char(&mut self, value: char) {
+        self.write_char_ne(value);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "serde")]
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// Errors for the checked operations of the readers and writers.<br/>
+/// The plain `read_*` functions still panic when there are not enough bytes, this is only used
+/// where the content itself can be invalid.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ByteError {
+    /// A string meant to be written as a C string contained a NUL byte at the given position
+    InteriorNul { position: usize },
+    /// A UTF-8 or modified UTF-8 string contained a malformed sequence at the given position
+    InvalidUtf8 { position: usize },
+    /// A UTF-16 string contained a surrogate code unit without its pair
+    InvalidUtf16 { unit: u16 },
+    /// A value was too long to have its length written in the prefix
+    TooLong { length: usize, maximum: usize },
+    /// A `bool` was stored as something other than `0` or `1`
+    InvalidBool { value: u8 },
+    /// A `char` was stored as a surrogate or a value above `char::MAX`
+    InvalidChar { value: u32 },
+    /// A value which is not allowed to be zero was zero
+    ZeroValue,
+    /// A tag did not match any of the known values
+    UnknownTag { tag: u64 },
+    /// A value was outside of the range the encoding or the target type can represent
+    OutOfRange,
+    /// A value did not have the length given for it elsewhere, such as in an earlier field
+    LengthMismatch { expected: usize, actual: usize },
+    /// The bytes did not match the constant expected at that point, such as a file signature
+    InvalidMagic,
+    /// An optional value was present when its condition was false, or missing when it was true
+    ConditionMismatch,
+    /// The bytes ended before the value did, needing `needed` bytes where only `remaining` were left
+    UnexpectedEnd { needed: usize, remaining: usize },
+    /// An error raised by a serde implementation, or a value the serde format cannot represent
+    #[cfg(feature = "serde")]
+    Custom { message: String },
+}
+
+impl Display for ByteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ByteError::InteriorNul { position } => {
+                write!(f, "Found an interior NUL byte at position {position}")
+            }
+            ByteError::InvalidUtf8 { position } => {
+                write!(f, "Found a malformed UTF-8 sequence at position {position}")
+            }
+            ByteError::InvalidUtf16 { unit } => {
+                write!(f, "Found an unpaired UTF-16 surrogate {unit:#06x}")
+            }
+            ByteError::TooLong { length, maximum } => {
+                write!(f, "Length of {length} does not fit the prefix, which allows at most {maximum}")
+            }
+            ByteError::InvalidBool { value } => {
+                write!(f, "Expected a bool of 0 or 1, found {value}")
+            }
+            ByteError::InvalidChar { value } => {
+                write!(f, "Found {value:#x}, which is not a valid char")
+            }
+            ByteError::ZeroValue => write!(f, "Found a zero where a non-zero value was expected"),
+            ByteError::UnknownTag { tag } => write!(f, "Found unknown tag {tag}"),
+            ByteError::OutOfRange => write!(f, "Value is out of range for the encoding"),
+            ByteError::LengthMismatch { expected, actual } => {
+                write!(f, "Expected a length of {expected}, found {actual}")
+            }
+            ByteError::InvalidMagic => write!(f, "Found bytes which do not match the expected magic"),
+            ByteError::ConditionMismatch => {
+                write!(f, "Found an optional value which does not match its condition")
+            }
+            ByteError::UnexpectedEnd { needed, remaining } => {
+                write!(f, "Expected {needed} more bytes, found only {remaining}")
+            }
+            #[cfg(feature = "serde")]
+            ByteError::Custom { message } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl core::error::Error for ByteError {}
@@ -0,0 +1,445 @@
+#![cfg(feature = "std")]
+use core::mem::{take, ManuallyDrop};
+use core::ptr;
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::vec;
+use std::vec::Vec;
+
+use crate::readers::traits::{
+    BigEndianByteReader, ByteReader, LittleEndianByteReader, NativeEndianByteReader,
+};
+use crate::view::View;
+use crate::writers::traits::{
+    BigEndianByteWriter, ByteWriter, LittleEndianByteWriter, NativeEndianByteWriter,
+};
+
+/// The buffer size used by [`HyperBufReader::new`] and [`HyperBufWriter::new`], the same as the standard library's
+pub const DEFAULT_BUFFER_SIZE: usize = 8 * 1024;
+
+/// The amount of bytes a read tops the buffer up towards, enough for the largest fixed-size value
+const LOOKAHEAD: usize = size_of::<u128>();
+
+/// A reader over any [`Read`], pulling more bytes from it as they are needed, so a large file can be parsed
+/// without loading it fully.<br/>
+/// Before each read with fewer than 16 bytes buffered, the inner reader is read from until 16 are, or it
+/// ends, so fixed-size reads are transparent however the inner reader splits up the bytes. Reads longer than
+/// 16 bytes, such as long strings, go through [`HyperBufReader::try_read_n`] or call
+/// [`HyperBufReader::fill`] first. [`HyperBufReader::set_single_read`] reads from the inner reader only once
+/// instead, for a blocking socket which hands out whole values and would otherwise be waited on for more.<br/>
+/// IO errors are returned by the `try_read_*` functions and [`HyperBufReader::fill`]. An error during a
+/// transparent refill is kept for [`HyperBufReader::take_error`], and the read panics as it would on any
+/// reader without enough bytes.
+///
+/// # Examples
+/// ```
+/// use hyper_byte::extensions::buffered::HyperBufReader;
+/// use hyper_byte::readers::traits::{BigEndianByteReader, ByteReader, LittleEndianByteReader};
+///
+/// let bytes: Vec<u8> = (0..100).collect();
+/// let mut reader = HyperBufReader::with_capacity(32, bytes.as_slice());
+///
+/// assert_eq!(reader.read_u64_be(), 0x0001_0203_0405_0607);
+/// reader.skip_n(84);
+/// assert_eq!(reader.read_u32_le(), 0x5F5E_5D5C);
+/// assert_eq!(reader.buffered(), 4);
+///
+/// // Only 4 bytes are left, so asking for more is an error rather than a panic
+/// assert!(reader.try_read_n(8).is_err());
+/// assert_eq!(reader.try_read_n(4).unwrap(), [96, 97, 98, 99]);
+/// ```
+pub struct HyperBufReader<R: Read> {
+    inner: R,
+    buffer: Vec<u8>,
+    start: usize,
+    end: usize,
+    error: Option<Error>,
+    single_read: bool,
+    // Set while a `try_read_*` reads the bytes it filled, so the read does not pull from the inner reader again
+    filled: bool,
+}
+
+impl<R: Read> HyperBufReader<R> {
+    /// Creates the reader with a buffer of [`DEFAULT_BUFFER_SIZE`] bytes
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, inner)
+    }
+
+    /// Creates the reader with a buffer of `capacity` bytes, which grows only for a larger [`HyperBufReader::fill`]
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self { inner, buffer: vec![0; capacity.max(LOOKAHEAD)], start: 0, end: 0, error: None, single_read: false, filled: false }
+    }
+
+    /// The amount of bytes the buffer holds
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// The amount of bytes pulled from the inner reader which have not been read yet
+    pub fn buffered(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Pulls from the inner reader until at least `amount` bytes are buffered, growing the buffer if it is smaller.<br/>
+    /// The inner reader ending first results in [`ErrorKind::UnexpectedEof`], keeping what was pulled
+    pub fn fill(&mut self, amount: usize) -> Result<(), Error> {
+        if self.buffered() >= amount {
+            return Ok(());
+        }
+        self.make_room(amount);
+        while self.buffered() < amount {
+            match self.inner.read(&mut self.buffer[self.end..]) {
+                Ok(0) => return Err(Error::from(ErrorKind::UnexpectedEof)),
+                Ok(read) => self.end += read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a [`View`], such as the types of [`crate::wire`], returning running out of bytes or an IO error
+    /// rather than panicking.<br/>
+    /// An error kept from a transparent refill is returned first
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::extensions::buffered::HyperBufReader;
+    /// use hyper_byte::wire::{U16Be, U32Be};
+    /// use std::io::ErrorKind;
+    ///
+    /// let mut reader = HyperBufReader::new([0u8, 0, 1, 0, 7].as_slice());
+    /// assert_eq!(reader.try_read_wire::<U32Be>().unwrap().get(), 256);
+    /// let error = reader.try_read_wire::<U16Be>().unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    /// // The byte pulled before running out is still there
+    /// assert_eq!(reader.try_read_wire::<u8>().unwrap(), 7);
+    /// ```
+    pub fn try_read_wire<T: View + Copy>(&mut self) -> Result<T, Error> {
+        self.read_filled(size_of::<T>(), |reader| reader.read_wire())
+    }
+
+    /// Reads `amount` bytes, such as a string after its length, returning running out of bytes or an IO error
+    /// rather than panicking, and growing the buffer if it is smaller.<br/>
+    /// An error kept from a transparent refill is returned first
+    pub fn try_read_n(&mut self, amount: usize) -> Result<Vec<u8>, Error> {
+        self.read_filled(amount, |reader| {
+            let bytes = reader.buffer[reader.start..reader.start + amount].to_vec();
+            reader.start += amount;
+            bytes
+        })
+    }
+
+    /// Reads from the inner reader only once before each read with fewer than 16 bytes buffered, taking
+    /// whatever it has ready, rather than until 16 bytes are buffered.<br/>
+    /// This suits a blocking socket which hands out whole values, which would otherwise be waited on for the
+    /// bytes of the next value, but a value split across two reads of the inner reader then panics
+    pub fn set_single_read(&mut self, single_read: bool) {
+        self.single_read = single_read;
+    }
+
+    /// Whether every byte has been read, pulling from the inner reader to find out if nothing is buffered
+    pub fn is_finished(&mut self) -> Result<bool, Error> {
+        match self.fill(1) {
+            Ok(()) => Ok(false),
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(true),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Takes the error of the last transparent refill which failed, if any
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    /// The inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// The inner reader, which should not be read from directly, as the buffered bytes would be skipped
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the inner reader, dropping any bytes still buffered
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Buffers `amount` bytes before running the read, which then does not pull from the inner reader
+    fn read_filled<T>(&mut self, amount: usize, read: impl FnOnce(&mut Self) -> T) -> Result<T, Error> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.fill(amount)?;
+        self.filled = true;
+        let value = read(self);
+        self.filled = false;
+        Ok(value)
+    }
+
+    /// Moves the buffered bytes to the front if `amount` bytes would not fit after them, growing the buffer if
+    /// it is smaller
+    fn make_room(&mut self, amount: usize) {
+        if self.start + amount > self.buffer.len() {
+            self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+            if amount > self.buffer.len() {
+                self.buffer.resize(amount, 0);
+            }
+        }
+    }
+
+    /// Reads from the inner reader until 16 bytes are buffered or it ends, or only once with
+    /// [`HyperBufReader::set_single_read`], keeping any error for [`HyperBufReader::take_error`]
+    fn refill(&mut self) {
+        self.make_room(LOOKAHEAD);
+        while self.buffered() < LOOKAHEAD {
+            match self.inner.read(&mut self.buffer[self.end..]) {
+                Ok(0) => return,
+                Ok(read) => self.end += read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.error = Some(error);
+                    return;
+                }
+            }
+            if self.single_read {
+                return;
+            }
+        }
+    }
+}
+
+impl<R: Read> ByteReader for HyperBufReader<R> {
+    /// The bytes buffered, after reading from the inner reader if there are fewer than 16
+    fn byte_array(&mut self) -> &[u8] {
+        if !self.filled && self.buffered() < LOOKAHEAD {
+            self.refill();
+        }
+        &self.buffer[self.start..self.end]
+    }
+
+    unsafe fn advance(&mut self, advancement: usize, _: usize) {
+        self.start += advancement;
+    }
+
+    /// Skips the bytes buffered and then pulls the rest from the inner reader, so it can go past the buffer</br>
+    /// Panics if there is not enough space
+    fn skip_n(&mut self, byte_size: usize) {
+        let mut remaining = byte_size;
+        loop {
+            let skipped = remaining.min(self.buffered());
+            self.start += skipped;
+            remaining -= skipped;
+            if remaining == 0 {
+                return;
+            }
+            if let Err(error) = self.fill(1) {
+                if error.kind() != ErrorKind::UnexpectedEof {
+                    self.error = Some(error);
+                }
+                panic!("Attempted to skip bytes of an array without space in the array.");
+            }
+        }
+    }
+}
+
+impl<R: Read + Seek> Seek for HyperBufReader<R> {
+    /// Seeks the inner reader, discarding the buffer, unless the position is within what is buffered already
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+        if let SeekFrom::Current(offset) = position {
+            let target = isize::try_from(offset).ok().and_then(|offset| self.start.checked_add_signed(offset));
+            if let Some(target) = target.filter(|&target| target <= self.end) {
+                self.start = target;
+                return self.inner.stream_position().map(|end| end - (self.end - target) as u64);
+            }
+        }
+        let position = match position {
+            // The inner reader is ahead by what is buffered
+            SeekFrom::Current(offset) => self.inner.seek(SeekFrom::Current(offset - self.buffered() as i64))?,
+            position => self.inner.seek(position)?,
+        };
+        self.start = 0;
+        self.end = 0;
+        Ok(position)
+    }
+}
+
+impl<R: Read> BigEndianByteReader for HyperBufReader<R> {}
+impl<R: Read> LittleEndianByteReader for HyperBufReader<R> {}
+impl<R: Read> NativeEndianByteReader for HyperBufReader<R> {}
+
+/// A writer over any [`Write`], handing the bytes on to it each time the buffer fills up, so a large export
+/// does not have to be held in memory.<br/>
+/// IO errors are returned by [`HyperBufWriter::flush`] and [`HyperBufWriter::into_inner`]. An error while
+/// handing on a full buffer is kept for those, with the bytes not yet written staying buffered. While the
+/// error is kept, the bytes of later writes are dropped rather than buffered, so the buffer stays bounded
+/// however long the writes go on, as the output past the error is broken either way.
+/// [`HyperBufWriter::take_error`] checks for the error between writes.<br/>
+/// Dropping the writer hands on what is left, ignoring any error, so [`HyperBufWriter::flush`] is called
+/// first wherever errors matter.
+///
+/// # Examples
+/// ```
+/// use hyper_byte::extensions::buffered::HyperBufWriter;
+/// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter, LittleEndianByteWriter};
+///
+/// let mut writer = HyperBufWriter::with_capacity(16, Vec::new());
+/// writer.write_u64_be(1);
+/// writer.write_u32_le(2);
+/// // Nothing has been handed on yet
+/// assert_eq!(writer.buffered(), 12);
+/// assert!(writer.get_ref().is_empty());
+///
+/// writer.write_u64_be(3);
+/// assert_eq!(writer.get_ref().len(), 20);
+///
+/// writer.write_u16_le(4);
+/// let output = writer.into_inner().unwrap();
+/// assert_eq!(output.len(), 22);
+/// assert_eq!(output[8..12], [2, 0, 0, 0]);
+/// ```
+pub struct HyperBufWriter<W: Write> {
+    inner: W,
+    buffer: Vec<u8>,
+    capacity: usize,
+    error: Option<Error>,
+}
+
+impl<W: Write> HyperBufWriter<W> {
+    /// Creates the writer with a buffer of [`DEFAULT_BUFFER_SIZE`] bytes
+    pub fn new(inner: W) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, inner)
+    }
+
+    /// Creates the writer, handing the bytes on once `capacity` or more are buffered
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self { inner, buffer: Vec::with_capacity(capacity), capacity, error: None }
+    }
+
+    /// The amount of bytes buffered before they are handed on
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The amount of bytes written which have not been handed on yet
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Hands every buffered byte on to the inner writer and flushes it.<br/>
+    /// An error kept from handing on a full buffer is returned first, and a later call tries again
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.write_buffer()?;
+        self.inner.flush()
+    }
+
+    /// Takes the error kept from handing on a full buffer, if any, after which writes are buffered again
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    /// Flushes and unwraps the inner writer.<br/>
+    /// On an error the writer is dropped, which tries once more to hand on what is left
+    pub fn into_inner(mut self) -> Result<W, Error> {
+        self.flush()?;
+        let mut this = ManuallyDrop::new(self);
+        drop(take(&mut this.buffer));
+        drop(this.error.take());
+        // SAFETY: The writer is never dropped, so the inner writer is only moved out of it this once
+        Ok(unsafe { ptr::read(&this.inner) })
+    }
+
+    /// The inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// The inner writer, which should not be written to directly, as the buffered bytes would come after
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Hands the buffered bytes on, keeping any which could not be written
+    fn write_buffer(&mut self) -> Result<(), Error> {
+        let mut written = 0;
+        let result = loop {
+            if written == self.buffer.len() {
+                break Ok(());
+            }
+            match self.inner.write(&self.buffer[written..]) {
+                Ok(0) => break Err(Error::from(ErrorKind::WriteZero)),
+                Ok(amount) => written += amount,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => break Err(error),
+            }
+        };
+        self.buffer.drain(..written);
+        result
+    }
+
+    /// Hands the buffered bytes on once there are enough, keeping the error if it fails.<br/>
+    /// Nothing more is handed on after an error until [`HyperBufWriter::flush`] is called
+    #[inline(always)]
+    fn write_if_full(&mut self) {
+        if self.buffer.len() >= self.capacity
+            && self.error.is_none()
+            && let Err(error) = self.write_buffer()
+        {
+            self.error = Some(error);
+        }
+    }
+}
+
+impl<W: Write> Drop for HyperBufWriter<W> {
+    fn drop(&mut self) {
+        if self.error.is_none() {
+            let _ = self.write_buffer();
+        }
+    }
+}
+
+impl<W: Write> ByteWriter for HyperBufWriter<W> {
+    /// The bytes which have not been handed on yet, dropping the inner writer
+    fn to_vec(mut self) -> Vec<u8> {
+        take(&mut self.buffer)
+    }
+
+    /// The bytes which have not been handed on yet
+    fn as_slice(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// The buffer, which bytes are pushed to without the check for a kept error
+    fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+        self.write_if_full();
+        &mut self.buffer
+    }
+
+    /// Drops the bytes while an error is kept
+    #[inline(always)]
+    fn put_bytes(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            self.buffer.extend_from_slice(bytes);
+            self.write_if_full();
+        }
+    }
+
+    /// Drops the zeroes while an error is kept
+    #[inline(always)]
+    fn put_zeros(&mut self, amount: usize) {
+        if self.error.is_none() {
+            self.buffer.resize(self.buffer.len() + amount, 0);
+            self.write_if_full();
+        }
+    }
+}
+
+impl<W: Write> BigEndianByteWriter for HyperBufWriter<W> {}
+impl<W: Write> LittleEndianByteWriter for HyperBufWriter<W> {}
+impl<W: Write> NativeEndianByteWriter for HyperBufWriter<W> {}
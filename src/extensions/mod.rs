@@ -1 +1,2 @@
+pub mod buffered;
 pub mod tcp_extensions;
\ No newline at end of file
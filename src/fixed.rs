@@ -0,0 +1,93 @@
+//! Options for the fixed-point and normalised integer writers, such as `write_q16_16_be` or `write_unorm16_le`.<br/>
+//! Reading these formats is exact, so only writing needs to decide how to round and what to do
+//! with values the format cannot hold.
+
+use crate::error::ByteError;
+
+/// How a real number is rounded onto the integer steps of a fixed-point format
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds to the nearest step, with halfway cases rounded away from zero
+    #[default]
+    Nearest,
+    /// Rounds towards negative infinity
+    Floor,
+    /// Rounds towards positive infinity
+    Ceil,
+    /// Rounds towards zero, dropping the fraction
+    TowardZero,
+}
+
+/// How a fixed-point or normalised value is written
+///
+/// # Examples
+/// ```
+/// use hyper_byte::fixed::{FixedMode, Rounding};
+/// use hyper_byte::writer::FastByteWriter;
+/// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+///
+/// let mut writer = FastByteWriter::new();
+/// // Clamped to the largest value a Q16.16 can hold
+/// writer.write_q16_16_be(40000.0, FixedMode::SATURATING).unwrap();
+/// // Refused, as it does not fit
+/// assert!(writer.write_q16_16_be(40000.0, FixedMode::STRICT).is_err());
+/// // Dropping the part which does not fit in 14 fractional bits
+/// writer.write_f2dot14_be(0.99999, FixedMode { rounding: Rounding::Floor, saturate: false }).unwrap();
+///
+/// assert_eq!(writer.to_vec(), [0x7F, 0xFF, 0xFF, 0xFF, 0x3F, 0xFF]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FixedMode {
+    pub rounding: Rounding,
+    /// Whether values outside of the range of the format are clamped to it, instead of resulting in an error
+    pub saturate: bool,
+}
+
+impl FixedMode {
+    /// Rounds to the nearest step and clamps values outside of the range
+    pub const SATURATING: FixedMode = FixedMode {
+        rounding: Rounding::Nearest,
+        saturate: true,
+    };
+
+    /// Rounds to the nearest step and errors on values outside of the range
+    pub const STRICT: FixedMode = FixedMode {
+        rounding: Rounding::Nearest,
+        saturate: false,
+    };
+}
+
+/// Scales `value` and rounds it onto an integer within `minimum..=maximum`.<br/>
+/// NaN can never be represented, so it results in an error even when saturating.
+#[inline(always)]
+pub(crate) fn quantize(value: f64, scale: f64, minimum: i64, maximum: i64, mode: FixedMode) -> Result<i64, ByteError> {
+    if value.is_nan() {
+        return Err(ByteError::OutOfRange);
+    }
+    // Anything further out is out of range regardless of rounding, and this keeps the casts exact
+    let scaled = (value * scale).clamp(minimum as f64 - 1.0, maximum as f64 + 1.0);
+    let truncated = scaled as i64;
+    let rounded = match mode.rounding {
+        Rounding::TowardZero => truncated,
+        Rounding::Floor if (truncated as f64) > scaled => truncated - 1,
+        Rounding::Ceil if (truncated as f64) < scaled => truncated + 1,
+        Rounding::Floor | Rounding::Ceil => truncated,
+        Rounding::Nearest => {
+            let fraction = scaled - truncated as f64;
+            if fraction >= 0.5 {
+                truncated + 1
+            } else if fraction <= -0.5 {
+                truncated - 1
+            } else {
+                truncated
+            }
+        }
+    };
+    if (minimum..=maximum).contains(&rounded) {
+        Ok(rounded)
+    } else if mode.saturate {
+        Ok(rounded.clamp(minimum, maximum))
+    } else {
+        Err(ByteError::OutOfRange)
+    }
+}
@@ -1,4 +1,12 @@
+use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
+use core::ffi::CStr;
+#[cfg(feature = "std")]
+use std::io::{Error, Seek, SeekFrom};
+
+#[cfg(feature = "std")]
+use crate::reader::seek_position;
 
 use crate::readers::traits::{
     BigEndianByteReader, ByteReader, LittleEndianByteReader, NativeEndianByteReader,
@@ -6,163 +14,399 @@ use crate::readers::traits::{
 use crate::writers::traits::{
     BigEndianByteWriter, ByteWriter, LittleEndianByteWriter, NativeEndianByteWriter,
 };
-
-pub struct NetworkStream {
-    src: Vec<u8>,
-    index: usize,
-}
-
-impl NetworkStream {
-    pub fn new(src: Vec<u8>) -> Self {
-        Self { src, index: 0 }
-    }
+use crate::search::find_byte;
+
+/// A compact threshold suited to most streams, for `with_compact_threshold`, as `new` only compacts when
+/// asked to
+pub const DEFAULT_COMPACT_THRESHOLD: usize = 8 * 1024;
+
+/// Defines a stream from its name, the byte order named in its documentation, and the endian reader and
+/// writer traits it implements
+macro_rules! stream {
+    ($name:ident, $order:literal, $($reader:ident $writer:ident),+) => {
+        #[doc = concat!("A stream of bytes written at the back and read from the front, in ", $order, " order.<br/>")]
+        /// Every byte written is kept unless a compact threshold is given to `with_compact_threshold`. Once more
+        /// than the threshold of bytes has been read, the next write then drops the bytes read so far, after
+        /// which [`ByteWriter::as_slice`] and [`ByteWriter::to_vec`] only hold the bytes from there on and the
+        /// bytes dropped can no longer be moved back to.
+        pub struct $name {
+            src: Vec<u8>,
+            index: usize,
+            compact_threshold: usize,
+            // The amount of bytes dropped from the front by compacting, so positions stay the same
+            released: usize,
+        }
+
+        impl $name {
+            /// Creates the stream, keeping every byte written until [`Self::compact`] or [`Self::clear`] is called
+            pub fn new(src: Vec<u8>) -> Self {
+                Self::with_compact_threshold(src, usize::MAX)
+            }
+
+            /// Creates the stream, compacting once more than `compact_threshold` bytes have been consumed, such
+            /// as [`DEFAULT_COMPACT_THRESHOLD`].<br/>
+            /// A threshold of [`usize::MAX`] leaves compacting to [`Self::compact`] alone
+            pub fn with_compact_threshold(src: Vec<u8>, compact_threshold: usize) -> Self {
+                Self { src, index: 0, compact_threshold, released: 0 }
+            }
+
+            /// The amount of consumed bytes the stream holds on to before compacting
+            pub const fn compact_threshold(&self) -> usize {
+                self.compact_threshold
+            }
+
+            /// Sets the amount of consumed bytes the stream holds on to before compacting
+            pub fn set_compact_threshold(&mut self, compact_threshold: usize) {
+                self.compact_threshold = compact_threshold;
+            }
+
+            /// The amount of bytes written which have not been read yet
+            pub fn len_unread(&self) -> usize {
+                self.src.len() - self.index
+            }
+
+            /// Moves the unread bytes to the front, releasing the bytes already read for reuse
+            pub fn compact(&mut self) {
+                self.src.drain(..self.index);
+                self.released += self.index;
+                self.index = 0;
+            }
+
+            /// Discards every byte, read or not
+            pub fn clear(&mut self) {
+                self.released += self.src.len();
+                self.src.clear();
+                self.index = 0;
+            }
+
+            /// The amount of bytes read so far, including those released by compacting
+            pub fn position(&self) -> usize {
+                self.released + self.index
+            }
+
+            /// Moves to the position, so the following reads start from there.<br/>
+            /// Positions are counted the same as [`Self::position`], so a position before the bytes released
+            /// by compacting cannot be moved back to</br>
+            /// Panics if the position is released or past the end of the bytes written
+            pub fn set_position(&mut self, position: usize) {
+                match position.checked_sub(self.released) {
+                    Some(index) if index <= self.src.len() => self.index = index,
+                    _ => panic!("Attempted to move to a position outside of the bytes held by the stream."),
+                }
+            }
+
+            /// Reads every byte up to the first [`delimiter`], advancing the stream past the delimiter</br>
+            /// The delimiter itself is consumed, but not returned. The bytes are borrowed from the stream rather than
+            /// copied. Returns [`None`] without advancing if there is no delimiter
+            pub fn read_until(&mut self, delimiter: u8) -> Option<&[u8]> {
+                let start = self.index;
+                let index = find_byte(&self.src[start..], delimiter)?;
+                self.index += index + 1;
+                Some(&self.src[start..start + index])
+            }
+
+            /// Reads a NUL-terminated string, advancing the stream past the terminator</br>
+            /// The string is borrowed from the stream rather than copied. Returns [`None`] without advancing if there
+            /// is no terminator
+            pub fn read_cstr(&mut self) -> Option<&CStr> {
+                let start = self.index;
+                let index = find_byte(&self.src[start..], 0)?;
+                self.index += index + 1;
+                // SAFETY: The bytes end at the first NUL, so it is the only one inside of them
+                Some(unsafe { CStr::from_bytes_with_nul_unchecked(&self.src[start..=start + index]) })
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Seek for $name {
+            /// Seeks within the bytes held, where a position which was released or is past the end results in
+            /// [`std::io::ErrorKind::InvalidInput`]
+            fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+                let position = seek_position(position, self.position(), self.released, self.released + self.src.len())?;
+                self.set_position(position);
+                Ok(position as u64)
+            }
+        }
+
+        impl ByteReader for $name {
+            fn byte_array(&mut self) -> &[u8] {
+                &self.src[self.index..]
+            }
+
+            unsafe fn advance(&mut self, advancement: usize, _: usize) {
+                self.index += advancement;
+            }
+        }
+
+        impl ByteWriter for $name {
+            fn to_vec(self) -> Vec<u8> {
+                self.src
+            }
+
+            fn as_slice(&self) -> &[u8] {
+                &self.src
+            }
+
+            /// Compacts before handing out the bytes to write to once more than the compact threshold has been
+            /// read, so consumed bytes are not kept forever, dropping them all at once if nothing is left unread
+            fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+                if self.index > self.compact_threshold {
+                    if self.index == self.src.len() {
+                        self.clear();
+                    } else {
+                        self.compact();
+                    }
+                }
+                &mut self.src
+            }
+        }
+
+        $(
+            impl $reader for $name {}
+            impl $writer for $name {}
+        )+
+    };
 }
 
-impl ByteReader for NetworkStream {
-    fn byte_array(&mut self) -> &[u8] {
-        &self.src[self.index..]
-    }
-
-    unsafe fn advance(&mut self, advancement: usize, _: usize) {
-        self.index += advancement;
-    }
+stream!(NetworkStream, "big-endian", BigEndianByteReader BigEndianByteWriter);
+stream!(LittleStream, "little-endian", LittleEndianByteReader LittleEndianByteWriter);
+stream!(NativeStream, "native-endian", NativeEndianByteReader NativeEndianByteWriter);
+stream!(
+    HyperStream, "any byte",
+    BigEndianByteReader BigEndianByteWriter,
+    LittleEndianByteReader LittleEndianByteWriter,
+    NativeEndianByteReader NativeEndianByteWriter
+);
+
+/// A stream over a fixed power-of-two capacity, which wraps around rather than growing, bounding the memory
+/// held per connection.<br/>
+/// Reads panic when there is not enough written and writes panic when there is not enough space, just as the
+/// other readers do, so [`RingStream::free`] or [`RingStream::push_slice`] are used when either could happen.<br/>
+/// Every write goes through [`ByteWriter::put_bytes`] or [`ByteWriter::put_zeros`], which check the space
+/// first, so [`ByteWriter::as_mut_vec`] panics rather than handing out bytes which could grow past the
+/// capacity. Reads see every unread byte in order, as the ring is rotated to start at the front the first
+/// time a read is made while the unread bytes wrap around.
+///
+/// # Examples
+/// ```
+/// use hyper_byte::hyper_stream::RingStream;
+/// use hyper_byte::readers::traits::BigEndianByteReader;
+/// use hyper_byte::writers::traits::BigEndianByteWriter;
+///
+/// let mut ring = RingStream::new(8);
+/// ring.write_u32_be(1);
+/// ring.write_u16_be(2);
+/// assert_eq!(ring.read_u32_be(), 1);
+/// // Wraps around to the start, as the first 4 bytes were read
+/// ring.write_u32_be(3);
+/// assert_eq!(ring.free(), 2);
+///
+/// let (front, back) = ring.as_slices();
+/// assert_eq!((front, back), (&[0, 2, 0, 0][..], &[0, 3][..]));
+/// assert_eq!(ring.read_u16_be(), 2);
+/// assert_eq!(ring.read_u32_be(), 3);
+/// assert!(ring.is_empty());
+/// ```
+pub struct RingStream {
+    buffer: Box<[u8]>,
+    head: usize,
+    len: usize,
 }
 
-impl BigEndianByteReader for NetworkStream {}
-
-impl ByteWriter for NetworkStream {
-    fn to_vec(self) -> Vec<u8> {
-        self.src
+impl RingStream {
+    /// Creates an empty ring holding up to `capacity` bytes</br>
+    /// Panics if the capacity is not a power of two
+    pub fn new(capacity: usize) -> Self {
+        if !capacity.is_power_of_two() {
+            panic!("Attempted to create a ring with a capacity which is not a power of two.");
+        }
+        Self { buffer: vec![0; capacity].into_boxed_slice(), head: 0, len: 0 }
     }
 
-    fn as_slice(&self) -> &[u8] {
-        &self.src
+    /// The amount of bytes the ring holds when full
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
     }
 
-    fn as_mut_vec(&mut self) -> &mut Vec<u8> {
-        &mut self.src
+    /// The amount of bytes written which have not been read yet
+    pub fn len(&self) -> usize {
+        self.len
     }
-}
-
-impl BigEndianByteWriter for NetworkStream {}
-
-pub struct LittleStream {
-    src: Vec<u8>,
-    index: usize,
-}
 
-impl LittleStream {
-    pub fn new(src: Vec<u8>) -> Self {
-        Self { src, index: 0 }
+    /// Whether everything written has been read
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-}
 
-impl ByteReader for LittleStream {
-    fn byte_array(&mut self) -> &[u8] {
-        &self.src[self.index..]
+    /// Whether no more bytes can be written until some are read
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
     }
 
-    unsafe fn advance(&mut self, advancement: usize, _: usize) {
-        self.index += advancement;
+    /// The amount of bytes which can be written before the ring is full
+    pub fn free(&self) -> usize {
+        self.capacity() - self.len()
     }
-}
-
-impl LittleEndianByteReader for LittleStream {}
 
-impl ByteWriter for LittleStream {
-    fn to_vec(self) -> Vec<u8> {
-        self.src
+    /// Discards every unread byte
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
     }
 
-    fn as_slice(&self) -> &[u8] {
-        &self.src
+    /// The unread bytes in order, as the part up to the wrap point followed by the part after it, such as for
+    /// vectored writes to a socket, after which the bytes written out are skipped
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        let end = self.head + self.len;
+        if end <= self.capacity() {
+            (&self.buffer[self.head..end], &[])
+        } else {
+            let (back, front) = self.buffer.split_at(self.head);
+            (front, &back[..end - self.capacity()])
+        }
     }
 
-    fn as_mut_vec(&mut self) -> &mut Vec<u8> {
-        &mut self.src
+    /// Writes as much of the bytes as there is space for, returning how many were written
+    pub fn push_slice(&mut self, bytes: &[u8]) -> usize {
+        let amount = bytes.len().min(self.free());
+        self.copy_in(&bytes[..amount]);
+        amount
     }
-}
-
-impl LittleEndianByteWriter for LittleStream {}
-
-pub struct NativeStream {
-    src: Vec<u8>,
-    index: usize,
-}
 
-impl NativeStream {
-    pub fn new(src: Vec<u8>) -> Self {
-        Self { src, index: 0 }
+    /// Reads as many bytes as are available into `bytes`, returning how many were read
+    pub fn pop_slice(&mut self, bytes: &mut [u8]) -> usize {
+        let (front, back) = self.as_slices();
+        let amount = bytes.len().min(front.len() + back.len());
+        let split = amount.min(front.len());
+        bytes[..split].copy_from_slice(&front[..split]);
+        bytes[split..amount].copy_from_slice(&back[..amount - split]);
+        self.consume(amount);
+        amount
     }
-}
 
-impl ByteReader for NativeStream {
-    fn byte_array(&mut self) -> &[u8] {
-        &self.src[self.index..]
+    /// Reads every byte up to the first [`delimiter`], advancing the ring past the delimiter</br>
+    /// The delimiter itself is consumed, but not returned. The bytes are borrowed from the ring rather than copied,
+    /// rotating it first if they wrap around. Returns [`None`] without advancing if there is no delimiter
+    pub fn read_until(&mut self, delimiter: u8) -> Option<&[u8]> {
+        let index = find_byte(self.byte_array(), delimiter)?;
+        let start = self.head;
+        self.consume(index + 1);
+        Some(&self.buffer[start..start + index])
     }
 
-    unsafe fn advance(&mut self, advancement: usize, _: usize) {
-        self.index += advancement;
+    /// Reads a NUL-terminated string, advancing the ring past the terminator</br>
+    /// The string is borrowed from the ring rather than copied, rotating it first if it wraps around. Returns
+    /// [`None`] without advancing if there is no terminator
+    pub fn read_cstr(&mut self) -> Option<&CStr> {
+        let index = find_byte(self.byte_array(), 0)?;
+        let start = self.head;
+        self.consume(index + 1);
+        // SAFETY: The bytes end at the first NUL, so it is the only one inside of them
+        Some(unsafe { CStr::from_bytes_with_nul_unchecked(&self.buffer[start..=start + index]) })
     }
-}
-
-impl NativeEndianByteReader for NativeStream {}
 
-impl ByteWriter for NativeStream {
-    fn to_vec(self) -> Vec<u8> {
-        self.src
+    /// Copies the bytes in after the unread ones, which must fit
+    #[inline(always)]
+    fn copy_in(&mut self, bytes: &[u8]) {
+        let (back, front) = self.free_slices(bytes.len());
+        let split = back.len();
+        back.copy_from_slice(&bytes[..split]);
+        front.copy_from_slice(&bytes[split..]);
+        self.len += bytes.len();
     }
 
-    fn as_slice(&self) -> &[u8] {
-        &self.src
+    /// The next `amount` free bytes after the unread ones, as the part up to the wrap point followed by the
+    /// part after it, which must fit
+    #[inline(always)]
+    fn free_slices(&mut self, amount: usize) -> (&mut [u8], &mut [u8]) {
+        let tail = (self.head + self.len) & (self.capacity() - 1);
+        let split = amount.min(self.capacity() - tail);
+        let (front, back) = self.buffer.split_at_mut(tail);
+        (&mut back[..split], &mut front[..amount - split])
     }
 
-    fn as_mut_vec(&mut self) -> &mut Vec<u8> {
-        &mut self.src
+    /// Panics if `amount` more bytes do not fit
+    #[inline(always)]
+    fn check_space(&self, amount: usize) {
+        if amount > self.free() {
+            panic!("Attempted to write bytes to a ring without enough space in the ring.");
+        }
     }
-}
-
-impl NativeEndianByteWriter for NativeStream {}
-
-pub struct HyperStream {
-    src: Vec<u8>,
-    index: usize,
-}
 
-impl HyperStream {
-    pub fn new(src: Vec<u8>) -> Self {
-        Self { src, index: 0 }
+    /// Marks `amount` unread bytes as read
+    #[inline(always)]
+    fn consume(&mut self, amount: usize) {
+        self.len -= amount;
+        // Starting over when empty keeps the following reads contiguous for longer
+        self.head = if self.len == 0 { 0 } else { (self.head + amount) & (self.capacity() - 1) };
     }
 }
 
-impl ByteReader for HyperStream {
+impl ByteReader for RingStream {
+    /// Every unread byte in order, rotating the ring to start at the front first if they wrap around, after
+    /// which the reads are contiguous until the writes wrap around again
     fn byte_array(&mut self) -> &[u8] {
-        &self.src[self.index..]
+        if self.head + self.len > self.capacity() {
+            self.buffer.rotate_left(self.head);
+            self.head = 0;
+        }
+        &self.buffer[self.head..self.head + self.len]
     }
 
     unsafe fn advance(&mut self, advancement: usize, _: usize) {
-        self.index += advancement;
+        self.consume(advancement);
+    }
+
+    /// Skips across the wrap point as well</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn skip_n(&mut self, byte_size: usize) {
+        if byte_size > self.len {
+            panic!("Attempted to skip bytes of a ring without enough written to the ring.");
+        }
+        self.consume(byte_size);
     }
 }
 
-impl BigEndianByteReader for HyperStream {}
-impl LittleEndianByteReader for HyperStream {}
-impl NativeEndianByteReader for HyperStream {}
+impl BigEndianByteReader for RingStream {}
+impl LittleEndianByteReader for RingStream {}
+impl NativeEndianByteReader for RingStream {}
 
-impl ByteWriter for HyperStream {
+impl ByteWriter for RingStream {
+    /// The unread bytes in order
     fn to_vec(self) -> Vec<u8> {
-        self.src
+        let (front, back) = self.as_slices();
+        [front, back].concat()
     }
 
+    /// The unread bytes up to the wrap point, [`RingStream::as_slices`] gives all of them
     fn as_slice(&self) -> &[u8] {
-        &self.src
+        &self.buffer[self.head..(self.head + self.len).min(self.capacity())]
     }
 
+    /// Panics, as the ring holds no [`Vec`] which could grow past its capacity
     fn as_mut_vec(&mut self) -> &mut Vec<u8> {
-        &mut self.src
+        panic!("Attempted to write to the bytes of a ring, which only takes bytes through put_bytes.");
+    }
+
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn put_bytes(&mut self, bytes: &[u8]) {
+        self.check_space(bytes.len());
+        self.copy_in(bytes);
+    }
+
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn put_zeros(&mut self, amount: usize) {
+        self.check_space(amount);
+        let (back, front) = self.free_slices(amount);
+        back.fill(0);
+        front.fill(0);
+        self.len += amount;
     }
 }
 
-impl BigEndianByteWriter for HyperStream {}
-impl LittleEndianByteWriter for HyperStream {}
-impl NativeEndianByteWriter for HyperStream {}
+impl BigEndianByteWriter for RingStream {}
+impl LittleEndianByteWriter for RingStream {}
+impl NativeEndianByteWriter for RingStream {}
@@ -0,0 +1,216 @@
+//! Compatibility with Java's `DataInput` and `DataOutput`, as written by `DataOutputStream`.<br/>
+//! The numeric types are plain big-endian, so they are read with [`BigEndianByteReader`] and
+//! written with [`BigEndianByteWriter`]. This adds the encodings which differ, most notably the
+//! modified UTF-8 of `readUTF` and `writeUTF`.
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::java::{JavaDataInput, JavaDataOutput};
+//! use hyper_byte::reader::FastByteReader;
+//! use hyper_byte::readers::traits::BigEndianByteReader;
+//! use hyper_byte::writer::FastByteWriter;
+//! use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+//!
+//! let mut writer = FastByteWriter::new();
+//! writer.write_i32_be(42);
+//! writer.write_java_utf("hyper\0byte").unwrap();
+//! writer.write_java_boolean(true);
+//!
+//! let buffer = writer.to_vec();
+//! let mut reader = FastByteReader::new(&buffer);
+//!
+//! assert_eq!(reader.read_i32_be(), 42);
+//! assert_eq!(reader.read_java_utf().unwrap(), "hyper\0byte");
+//! assert!(reader.read_java_boolean());
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::char::decode_utf16;
+use crate::error::ByteError;
+use crate::readers::traits::BigEndianByteReader;
+use crate::writers::traits::BigEndianByteWriter;
+
+/// Decodes modified UTF-8 into UTF-16 code units, the way `DataInputStream.readUTF` does
+fn decode_modified_utf8(bytes: &[u8]) -> Result<Vec<u16>, ByteError> {
+    // Errors point at the start of the sequence, not the continuation byte which was wrong
+    let continuation = |position: usize, offset: usize| match bytes.get(position + offset) {
+        Some(&byte) if byte & 0xC0 == 0x80 => Ok((byte & 0x3F) as u16),
+        _ => Err(ByteError::InvalidUtf8 { position }),
+    };
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut position = 0;
+    while let Some(&byte) = bytes.get(position) {
+        let unit = match byte {
+            0x00..=0x7F => {
+                position += 1;
+                byte as u16
+            }
+            0xC0..=0xDF => {
+                let unit = ((byte & 0x1F) as u16) << 6 | continuation(position, 1)?;
+                position += 2;
+                unit
+            }
+            0xE0..=0xEF => {
+                let unit = ((byte & 0x0F) as u16) << 12 | continuation(position, 1)? << 6 | continuation(position, 2)?;
+                position += 3;
+                unit
+            }
+            _ => return Err(ByteError::InvalidUtf8 { position }),
+        };
+        units.push(unit);
+    }
+    Ok(units)
+}
+
+/// Encodes a string as modified UTF-8, where NUL takes two bytes and every UTF-16 code unit is encoded on its own
+fn encode_modified_utf8(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.len());
+    for unit in value.encode_utf16() {
+        match unit {
+            0x0001..=0x007F => bytes.push(unit as u8),
+            0x0000 | 0x0080..=0x07FF => {
+                bytes.extend([0xC0 | (unit >> 6) as u8, 0x80 | (unit & 0x3F) as u8]);
+            }
+            _ => bytes.extend([
+                0xE0 | (unit >> 12) as u8,
+                0x80 | ((unit >> 6) & 0x3F) as u8,
+                0x80 | (unit & 0x3F) as u8,
+            ]),
+        }
+    }
+    bytes
+}
+
+pub trait JavaDataInput: BigEndianByteReader {
+    /// Reads a string written by Java's `writeUTF`, advancing the readers forward by 2 bytes and the length in the prefix</br>
+    /// The string is prefixed by its length in bytes as a big-endian [`u16`], and is encoded as modified UTF-8</br>
+    /// Malformed bytes or unpaired surrogates result in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_java_utf(&mut self) -> Result<String, ByteError> {
+        let length = self.read_u16_be() as usize;
+        let byte_array = self.byte_array();
+        if byte_array.len() < length {
+            panic!("Attempted to read a modified UTF-8 string of an array without enough space within the array.");
+        }
+        let new_length = byte_array.len() - length;
+        let units = decode_modified_utf8(&byte_array[..length]);
+        // SAFETY: The length was checked against the array above
+        unsafe {
+            self.advance(length, new_length);
+        }
+        decode_utf16(units?)
+            .map(|result| result.map_err(|error| ByteError::InvalidUtf16 { unit: error.unpaired_surrogate() }))
+            .collect()
+    }
+
+    /// Reads a `char` written by Java's `writeChar`, advancing the readers forward by 2 bytes</br>
+    /// Java writes a single UTF-16 code unit, so a surrogate results in an error. Use `read_u16_be` to read those as they are</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_java_char(&mut self) -> Result<char, ByteError> {
+        let unit = self.read_u16_be();
+        char::from_u32(unit as u32).ok_or(ByteError::InvalidUtf16 { unit })
+    }
+
+    /// Reads a `boolean` written by Java's `writeBoolean`, advancing the readers forward by 1 byte</br>
+    /// As with `readBoolean`, any value other than `0` is `true`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_java_boolean(&mut self) -> bool {
+        self.read_u8_be() != 0
+    }
+}
+
+impl<R: BigEndianByteReader + ?Sized> JavaDataInput for R {}
+
+pub trait JavaDataOutput: BigEndianByteWriter {
+    /// For writing a string the way Java's `writeUTF` does, which `readUTF` can read back<br/>
+    /// Returns an error without writing anything if the modified UTF-8 takes more than [`u16::MAX`] bytes
+    /// # Examples
+    /// ```
+    /// use hyper_byte::java::{JavaDataInput, JavaDataOutput};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_java_utf(my_simple_tuple.0).unwrap();
+    /// writer.write_java_utf(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_java_utf().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_java_utf().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_java_utf(&mut self, value: &str) -> Result<(), ByteError> {
+        let bytes = encode_modified_utf8(value);
+        let length = u16::try_from(bytes.len()).map_err(|_| ByteError::TooLong {
+            length: bytes.len(),
+            maximum: u16::MAX as usize,
+        })?;
+        self.write_u16_be(length);
+        self.put_bytes(&bytes);
+        Ok(())
+    }
+
+    /// For writing a `char` the way Java's `writeChar` does, as a single big-endian UTF-16 code unit<br/>
+    /// Returns an error for a `char` outside of the Basic Multilingual Plane, as it does not fit in one code unit
+    /// # Examples
+    /// ```
+    /// use hyper_byte::java::{JavaDataInput, JavaDataOutput};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = ('h', 'ÿ');
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_java_char(my_simple_tuple.0).unwrap();
+    /// writer.write_java_char(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_java_char().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_java_char().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_java_char(&mut self, value: char) -> Result<(), ByteError> {
+        let unit = u16::try_from(value as u32).map_err(|_| ByteError::OutOfRange)?;
+        self.write_u16_be(unit);
+        Ok(())
+    }
+
+    /// For writing a `bool` the way Java's `writeBoolean` does, as a single byte of `0` or `1`
+    /// # Examples
+    /// ```
+    /// use hyper_byte::java::{JavaDataInput, JavaDataOutput};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (true, false);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_java_boolean(my_simple_tuple.0);
+    /// writer.write_java_boolean(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_java_boolean(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_java_boolean(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_java_boolean(&mut self, value: bool) {
+        self.write_u8_be(value as u8);
+    }
+}
+
+impl<W: BigEndianByteWriter + ?Sized> JavaDataOutput for W {}
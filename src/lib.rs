@@ -1,5 +1,7 @@
 #![no_std]
 extern crate alloc;
+#[cfg(test)]
+extern crate self as hyper_byte;
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -10,9 +12,25 @@ pub mod writer;
 pub mod writers;
 pub mod hyper_stream;
 pub mod extensions;
+pub mod error;
+pub mod fixed;
+pub mod java;
+pub mod dotnet;
+pub mod derive;
+pub mod endian;
+pub mod codec;
+pub mod wire;
+pub mod view;
+pub mod versioned;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod array;
+mod search;
+mod text;
+mod time;
 
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
 
 /// Unsafe, near zero cost transmutation of a byte array slice into an unsigned 8-bit integer using big-endianness.
 /// # Safety
@@ -484,6 +502,44 @@ pub unsafe fn read_f16_be(bytes: &[u8]) -> f16 {
     unsafe { f16::from_be_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
 }
 
+#[cfg(feature = "half")]
+/// Unsafe, near zero cost transmutation of a byte array slice into a 16-bit brain floating point using big-endianness.<br/>
+/// # Safety
+/// To make it "safe" and does not cause memory errors, you must ensure the input has at least 2 bytes prior to calling this.
+/// # Arguments
+/// * `bytes`: the byte array reference
+///
+/// returns: [`bf16`]
+///
+/// # Examples
+/// ```
+/// # #[cfg(miri)] fn main() {}
+/// # #[cfg(not(miri))]
+/// # fn main() {
+/// use half::bf16;
+/// use hyper_byte::read_bf16_be;
+///
+/// let slice = [0u8; 16];
+/// // PERFECTLY SAFE
+/// let first_value = unsafe { read_bf16_be(&slice[0..2]) };
+/// // NOT SAFE
+/// let second_value = unsafe { read_bf16_be(&slice[9..9]) };
+///
+///
+/// pub fn read_bf16_safe_be(array: &[u8], index: &mut usize) -> bf16 {
+///     // "SAFE" because even if the array is not of this length,
+///     // it will still at least panic and not cause undefined behaviour
+///     let third_value = unsafe { read_bf16_be(&array[*index..(*index+2)]) };
+///     *index += 2;
+///     third_value
+/// }
+/// # }
+/// ```
+#[inline(always)]
+pub unsafe fn read_bf16_be(bytes: &[u8]) -> bf16 {
+    unsafe { bf16::from_be_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
+}
+
 /// Unsafe, near zero cost transmutation of a byte array slice into a 32-bit floating point using big-endianness.<br/>
 /// # Safety
 /// To make it "safe" and does not cause memory errors, you must ensure the input has at least 4 bytes prior to calling this.
@@ -1026,6 +1082,44 @@ pub unsafe fn read_f16_le(bytes: &[u8]) -> f16 {
     unsafe { f16::from_le_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
 }
 
+#[cfg(feature = "half")]
+/// Unsafe, near zero cost transmutation of a byte array slice into a 16-bit brain floating point using little-endianness.<br/>
+/// # Safety
+/// To make it "safe" and does not cause memory errors, you must ensure the input has at least 2 bytes prior to calling this.
+/// # Arguments
+/// * `bytes`: the byte array reference
+///
+/// returns: [`bf16`]
+///
+/// # Examples
+/// ```
+/// # #[cfg(miri)] fn main() {}
+/// # #[cfg(not(miri))]
+/// # fn main() {
+/// use half::bf16;
+/// use hyper_byte::read_bf16_le;
+///
+/// let slice = [0u8; 16];
+/// // PERFECTLY SAFE
+/// let first_value = unsafe { read_bf16_le(&slice[0..2]) };
+/// // NOT SAFE
+/// let second_value = unsafe { read_bf16_le(&slice[9..9]) };
+///
+///
+/// pub fn read_bf16_safe_le(array: &[u8], index: &mut usize) -> bf16 {
+///     // "SAFE" because even if the array is not of this length,
+///     // it will still at least panic and not cause undefined behaviour
+///     let third_value = unsafe { read_bf16_le(&array[*index..(*index+2)]) };
+///     *index += 2;
+///     third_value
+/// }
+/// # }
+/// ```
+#[inline(always)]
+pub unsafe fn read_bf16_le(bytes: &[u8]) -> bf16 {
+    unsafe { bf16::from_le_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
+}
+
 /// Unsafe, near zero cost transmutation of a byte array slice into a 32-bit floating point using little-endianness.<br/>
 /// # Safety
 /// To make it "safe" and does not cause memory errors, you must ensure the input has at least 4 bytes prior to calling this.
@@ -1568,6 +1662,44 @@ pub unsafe fn read_f16_ne(bytes: &[u8]) -> f16 {
     unsafe { f16::from_ne_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
 }
 
+#[cfg(feature = "half")]
+/// Unsafe, near zero cost transmutation of a byte array slice into a 16-bit brain floating point using native-endianness.<br/>
+/// # Safety
+/// To make it "safe" and does not cause memory errors, you must ensure the input has at least 2 bytes prior to calling this.
+/// # Arguments
+/// * `bytes`: the byte array reference
+///
+/// returns: [`bf16`]
+///
+/// # Examples
+/// ```
+/// # #[cfg(miri)] fn main() {}
+/// # #[cfg(not(miri))]
+/// # fn main() {
+/// use half::bf16;
+/// use hyper_byte::read_bf16_ne;
+///
+/// let slice = [0u8; 16];
+/// // PERFECTLY SAFE
+/// let first_value = unsafe { read_bf16_ne(&slice[0..2]) };
+/// // NOT SAFE
+/// let second_value = unsafe { read_bf16_ne(&slice[9..9]) };
+///
+///
+/// pub fn read_bf16_safe_ne(array: &[u8], index: &mut usize) -> bf16 {
+///     // "SAFE" because even if the array is not of this length,
+///     // it will still at least panic and not cause undefined behaviour
+///     let third_value = unsafe { read_bf16_ne(&array[*index..(*index+2)]) };
+///     *index += 2;
+///     third_value
+/// }
+/// # }
+/// ```
+#[inline(always)]
+pub unsafe fn read_bf16_ne(bytes: &[u8]) -> bf16 {
+    unsafe { bf16::from_ne_bytes(*(bytes.as_ptr() as *const [u8; 2])) }
+}
+
 /// Unsafe, near zero cost transmutation of a byte array slice into a 32-bit floating point using little-endianness.<br/>
 /// # Safety
 /// To make it "safe" and does not cause memory errors, you must ensure the input has at least 4 bytes prior to calling this.
@@ -1645,12 +1777,17 @@ mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
     use super::*;
+    use crate::error::ByteError;
+    use crate::fixed::{FixedMode, Rounding};
+    use crate::java::{JavaDataInput, JavaDataOutput};
+    use crate::dotnet::{Decimal, DotNetBinaryReader, DotNetBinaryWriter};
+    use core::num::NonZeroU64;
     use crate::hyper_stream::HyperStream;
     use crate::reader::FastByteReader;
     use crate::readers::traits::*;
     use crate::writer::FastByteWriter;
     use crate::writers::traits::*;
-    use half::f16;
+    use half::{bf16, f16};
     #[cfg(feature = "std")]
     use core::hint;
     #[cfg(feature = "std")]
@@ -2003,6 +2140,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_brain_float_be() {
+        let bfloat16: bf16 = bf16::from_f32_const(10f32);
+        let bfloat16_array = bfloat16.to_be_bytes();
+        let bfloat16_result = unsafe { read_bf16_be(&bfloat16_array) };
+        assert_eq!(
+            bfloat16, bfloat16_result,
+            "Converting from bf16 (big-endian) byte array results in the same bf16 value"
+        );
+    }
+
+    #[test]
+    fn test_brain_float_le() {
+        let bfloat16: bf16 = bf16::from_f32_const(10f32);
+        let bfloat16_array = bfloat16.to_le_bytes();
+        let bfloat16_result = unsafe { read_bf16_le(&bfloat16_array) };
+        assert_eq!(
+            bfloat16, bfloat16_result,
+            "Converting from bf16 (little-endian) byte array results in the same bf16 value"
+        );
+    }
+
+    #[test]
+    fn test_brain_float_ne() {
+        let bfloat16: bf16 = bf16::from_f32_const(10f32);
+        let bfloat16_array = bfloat16.to_ne_bytes();
+        let bfloat16_result = unsafe { read_bf16_ne(&bfloat16_array) };
+        assert_eq!(
+            bfloat16, bfloat16_result,
+            "Converting from bf16 (native-endian) byte array results in the same bf16 value"
+        );
+    }
+
+    #[test]
+    fn half_conversions() {
+        let mut writer = FastByteWriter::new();
+        writer.write_f32_as_f16_le(1.0 / 3.0);
+        writer.write_f32_as_bf16_be(1.0 / 3.0);
+        writer.write_f32_slice_as_f16_ne(&[65520.0, -0.5]);
+        writer.write_f32_slice_as_bf16_le(&[3.0e38, 1.0e-3]);
+
+        let buffer = writer.to_vec();
+        assert_eq!(&buffer[..2], &f16::from_f32(1.0 / 3.0).to_le_bytes());
+        assert_eq!(&buffer[2..4], &bf16::from_f32(1.0 / 3.0).to_be_bytes());
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_f16_as_f32_le(), f16::from_f32(1.0 / 3.0).to_f32());
+        assert_eq!(reader.read_bf16_as_f32_be(), bf16::from_f32(1.0 / 3.0).to_f32());
+        assert_eq!(reader.read_f16_slice_as_f32_ne(2), [f32::INFINITY, -0.5]);
+        assert_eq!(
+            reader.read_bf16_slice_as_f32_le(2),
+            [bf16::from_f32(3.0e38).to_f32(), bf16::from_f32(1.0e-3).to_f32()]
+        );
+        assert!(reader.byte_array().is_empty());
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn native_endian_writes() {
+        let bfloat16 = bf16::from_f32_const(10.5f32);
+        let float16 = f16::from_f32_const(-2.25f32);
+        let mut writer = FastByteWriter::new();
+        writer.write_bf16_ne(bfloat16);
+        writer.write_f16_ne(float16);
+        writer.write_f32_as_bf16_ne(10.5);
+        writer.write_u32_ne(0x0102_0304);
+        writer.write_f64_ne(-1.5);
+
+        let buffer = writer.to_vec();
+        assert_eq!(&buffer[..2], &bfloat16.to_ne_bytes());
+        assert_eq!(&buffer[2..4], &float16.to_ne_bytes());
+        assert_eq!(&buffer[4..6], &bfloat16.to_ne_bytes());
+        assert_eq!(&buffer[6..10], &0x0102_0304u32.to_ne_bytes());
+        assert_eq!(&buffer[10..], &(-1.5f64).to_ne_bytes());
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_bf16_ne(), bfloat16);
+        assert_eq!(reader.read_f16_ne(), float16);
+        assert_eq!(reader.read_bf16_as_f32_ne(), 10.5);
+        assert_eq!(reader.read_u32_ne(), 0x0102_0304);
+        assert_eq!(reader.read_f64_ne(), -1.5);
+    }
+
     #[test]
     fn test_floating_points_be() {
         let float32 = 192f32;
@@ -2506,6 +2726,1130 @@ mod tests {
         assert_eq!(reader.read_n_ne(8 * 4), equate_bytes);
     }
 
+    #[test]
+    fn delimiters() {
+        let bytes = b"key=value;some longer key=some longer value;no delimiter";
+        let mut reader = FastByteReader::new(bytes);
+
+        assert_eq!(reader.find(b"value"), Some(4));
+        assert_eq!(reader.find(b"missing"), None);
+        assert_eq!(reader.find(b""), Some(0));
+        assert_eq!(reader.read_until_vec(b'='), Some(b"key".to_vec()));
+        assert_eq!(reader.skip_until(b';'), Some(5));
+        assert_eq!(reader.find(b"value;"), Some(28));
+        assert_eq!(reader.read_until_vec(b'='), Some(b"some longer key".to_vec()));
+        assert_eq!(reader.read_until(b';'), Some(&b"some longer value"[..]));
+        assert_eq!(reader.read_until(b';'), None);
+        assert_eq!(reader.skip_until(b';'), None);
+        assert_eq!(reader.read_n_ne(12), b"no delimiter".to_vec());
+
+        let mut reader = FastByteReader::new(bytes);
+        assert_eq!(reader.read_until(b'='), Some(&b"key"[..]));
+        assert_eq!(reader.read_until(b';'), Some(&b"value"[..]));
+        assert_eq!(reader.remaining(), b"some longer key=some longer value;no delimiter");
+
+        for position in 0..40 {
+            let mut bytes = [b'a'; 40];
+            bytes[position] = b'b';
+            let mut reader = FastByteReader::new(&bytes[..]);
+            assert_eq!(reader.find(b"b"), Some(position));
+            assert_eq!(reader.find(b"ab"), position.checked_sub(1));
+            assert_eq!(reader.skip_until(b'b'), Some(position));
+        }
+    }
+
+    #[test]
+    fn cstr() {
+        use crate::hyper_stream::NetworkStream;
+
+        let mut writer = FastByteWriter::new();
+        writer.write_cstr("hyper").unwrap();
+        writer.write_cstr("").unwrap();
+        writer.write_cstr("byte").unwrap();
+        assert!(matches!(
+            writer.write_cstr("nul\0inside"),
+            Err(ByteError::InteriorNul { position: 3 })
+        ));
+        writer.write_bytes_ne(b"unterminated");
+
+        let buffer = writer.to_vec();
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_cstring().unwrap().as_bytes(), b"hyper");
+        assert_eq!(reader.read_cstr().unwrap().to_bytes(), b"");
+        assert_eq!(reader.read_cstring().unwrap().as_bytes(), b"byte");
+        assert_eq!(reader.read_cstr(), None);
+        assert_eq!(reader.read_cstring(), None);
+        assert_eq!(reader.byte_array(), b"unterminated");
+
+        // Borrowed from the buffer, outliving the reader
+        let (hyper, empty) = {
+            let mut reader = FastByteReader::new(&buffer);
+            (reader.read_cstr().unwrap(), reader.read_cstr().unwrap())
+        };
+        assert_eq!(hyper.to_bytes(), b"hyper");
+        assert_eq!(hyper.as_ptr() as *const u8, buffer.as_ptr());
+        assert!(empty.is_empty());
+
+        // Borrowed from the stream, which keeps its position when there is no terminator
+        let mut stream = NetworkStream::new(buffer);
+        assert_eq!(stream.read_cstr().unwrap().to_bytes(), b"hyper");
+        stream.skip_n(1);
+        assert_eq!(stream.read_until(b'\0'), Some(&b"byte"[..]));
+        assert_eq!(stream.read_cstr(), None);
+        assert_eq!(stream.len_unread(), 12);
+    }
+
+    #[test]
+    fn utf16() {
+        let mut writer = FastByteWriter::new();
+        writer.write_utf16_le("hi 🦀");
+        writer.write_utf16_be_prefixed("hi 🦀").unwrap();
+        // A lone high surrogate followed by 'a'
+        writer.write_u16_le(0xD800);
+        writer.write_u16_le(0x0061);
+        writer.write_u16_be(0xD800);
+        writer.write_u16_be(0x0061);
+
+        let buffer = writer.to_vec();
+        assert_eq!(&buffer[..4], &[b'h', 0, b'i', 0]);
+        assert_eq!(&buffer[10..14], &[0, 5, 0, b'h']);
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_utf16_le(5).unwrap(), "hi 🦀");
+        assert_eq!(reader.read_utf16_be_prefixed().unwrap(), "hi 🦀");
+        assert!(matches!(
+            reader.read_utf16_le(2),
+            Err(ByteError::InvalidUtf16 { unit: 0xD800 })
+        ));
+        assert_eq!(reader.read_utf16_be_lossy(2), "\u{FFFD}a");
+        assert!(reader.byte_array().is_empty());
+
+        let long = "a".repeat(u16::MAX as usize + 1);
+        assert!(matches!(
+            FastByteWriter::new().write_utf16_le_prefixed(&long),
+            Err(ByteError::TooLong { length: 65536, maximum: 65535 })
+        ));
+    }
+
+    #[test]
+    fn tagged_values() {
+        let mut writer = FastByteWriter::new();
+        writer.write_bool(true);
+        writer.write_u8_ne(2);
+        writer.write_char_le('🦀');
+        writer.write_u32_le(0xD800);
+        writer.write_u32_be(0x110000);
+        writer.write_nonzero_u64_be(NonZeroU64::new(193).unwrap());
+        writer.write_i16_le(0);
+        writer.write_option(&Some(-5i8), |writer, &value| writer.write_i8_ne(value));
+        writer.write_option(&None::<i8>, |writer, &value| writer.write_i8_ne(value));
+        writer.write_u8_ne(7);
+        writer.write_result(
+            &Err::<u16, _>(true),
+            |writer, &value| writer.write_u16_le(value),
+            |writer, &error| writer.write_bool(error),
+        );
+
+        let buffer = writer.to_vec();
+        let mut reader = FastByteReader::new(&buffer);
+        assert!(reader.read_bool().unwrap());
+        assert!(matches!(reader.read_bool(), Err(ByteError::InvalidBool { value: 2 })));
+        assert_eq!(reader.read_char_le().unwrap(), '🦀');
+        assert!(matches!(reader.read_char_le(), Err(ByteError::InvalidChar { value: 0xD800 })));
+        assert!(matches!(reader.read_char_be(), Err(ByteError::InvalidChar { value: 0x110000 })));
+        assert_eq!(reader.read_nonzero_u64_be().unwrap().get(), 193);
+        assert!(matches!(reader.read_nonzero_i16_le(), Err(ByteError::ZeroValue)));
+        assert_eq!(reader.read_option(|reader| Ok(reader.read_i8_ne())).unwrap(), Some(-5));
+        assert_eq!(reader.read_option(|reader| Ok(reader.read_i8_ne())).unwrap(), None);
+        assert!(matches!(
+            reader.read_option(|reader| Ok(reader.read_i8_ne())),
+            Err(ByteError::UnknownTag { tag: 7 })
+        ));
+        assert_eq!(
+            reader
+                .read_result(|reader| Ok(reader.read_u16_le()), |reader| reader.read_bool())
+                .unwrap(),
+            Err(true)
+        );
+        assert!(reader.byte_array().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn network_addresses() {
+        use crate::reader::NetworkReader;
+        use crate::writer::NetworkWriter;
+        use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+        let bytes = [
+            0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E, // MAC
+            192, 168, 1, 20, 0x1F, 0x90, // 192.168.1.20:8080
+            0x20, 0x01, 0x0D, 0xB8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0x01, 0xBB, // [2001:db8::1]:443
+        ];
+        let mut reader = NetworkReader::new(&bytes);
+        assert_eq!(reader.read_mac(), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+        let v4 = reader.read_socket_addr_v4();
+        assert_eq!(v4, SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 20), 8080));
+        let v6 = reader.read_socket_addr_v6();
+        assert_eq!(
+            v6,
+            SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 443, 0, 0)
+        );
+        assert!(reader.byte_array().is_empty());
+
+        let mut writer = NetworkWriter::new();
+        writer.write_mac([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+        writer.write_socket_addr_v4(v4);
+        writer.write_socket_addr_v6(v6);
+        assert_eq!(writer.as_slice(), &bytes);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_encodings() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut writer = FastByteWriter::new();
+        writer.write_ntp_timestamp_be(UNIX_EPOCH + Duration::from_millis(500)).unwrap();
+        writer.write_filetime_le(UNIX_EPOCH).unwrap();
+        let buffer = writer.to_vec();
+        assert_eq!(&buffer[..8], &[0x83, 0xAA, 0x7E, 0x80, 0x80, 0, 0, 0]);
+        assert_eq!(&buffer[8..], &116_444_736_000_000_000u64.to_le_bytes());
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(
+            reader.read_ntp_timestamp_be().unwrap(),
+            UNIX_EPOCH + Duration::from_millis(500)
+        );
+        assert_eq!(reader.read_filetime_le().unwrap(), UNIX_EPOCH);
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        let after_2106 = UNIX_EPOCH + Duration::from_secs(u32::MAX as u64 + 1);
+        let after_2036 = UNIX_EPOCH + Duration::from_secs(2_085_978_496);
+        let mut writer = FastByteWriter::new();
+        assert!(matches!(writer.write_unix_secs_u64_le(before_epoch), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_unix_secs_u32_be(after_2106), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_ntp_timestamp_be(after_2036), Err(ByteError::OutOfRange)));
+        assert!(writer.is_empty());
+        writer.write_ntp_timestamp_be(before_epoch).unwrap();
+        writer.write_u64_be(1);
+        writer.write_u32_be(1_000_000_000);
+
+        let buffer = writer.to_vec();
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_ntp_timestamp_be().unwrap(), before_epoch);
+        assert!(matches!(reader.read_duration_be(), Err(ByteError::OutOfRange)));
+    }
+
+    #[test]
+    fn uuid_layouts() {
+        let rfc = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        ];
+        let guid = [
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        ];
+
+        let mut writer = FastByteWriter::new();
+        writer.write_uuid_be(rfc);
+        writer.write_guid_le(rfc);
+        assert_eq!(&writer[..16], &rfc);
+        assert_eq!(&writer[16..], &guid);
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(reader.read_uuid_be(), rfc);
+        assert_eq!(reader.read_guid_le(), rfc);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_typed() {
+        let uuid = uuid::Uuid::from_u128(0x00112233_4455_6677_8899_AABBCCDDEEFF);
+
+        let mut writer = FastByteWriter::new();
+        writer.write_uuid_typed_be(uuid);
+        writer.write_guid_typed_le(uuid);
+        assert_eq!(&writer[..16], uuid.as_bytes());
+        assert_eq!(&writer[16..], &uuid.to_bytes_le());
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(reader.read_uuid_typed_be(), uuid);
+        assert_eq!(reader.read_guid_typed_le(), uuid);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let floor = FixedMode { rounding: Rounding::Floor, saturate: false };
+        let ceil = FixedMode { rounding: Rounding::Ceil, saturate: false };
+        let toward_zero = FixedMode { rounding: Rounding::TowardZero, saturate: false };
+
+        let mut writer = FastByteWriter::new();
+        writer.write_q16_16_le(-1.00001, floor).unwrap();
+        writer.write_q16_16_le(-1.00001, ceil).unwrap();
+        writer.write_q16_16_le(-1.00001, toward_zero).unwrap();
+        writer.write_f2dot14_ne(-2.5, FixedMode::SATURATING).unwrap();
+        writer.write_unorm16_be(2.0, FixedMode::SATURATING).unwrap();
+        writer.write_snorm16_le(0.5, FixedMode::STRICT).unwrap();
+        writer.write_unorm8(0.5, FixedMode::STRICT).unwrap();
+        writer.write_snorm8(-3.0, FixedMode::SATURATING).unwrap();
+
+        assert!(matches!(writer.write_q16_16_be(f64::NAN, FixedMode::SATURATING), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_f2dot14_be(2.0, FixedMode::STRICT), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_unorm8(-0.01, FixedMode::STRICT), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_snorm8(1.01, FixedMode::STRICT), Err(ByteError::OutOfRange)));
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(reader.read_i32_le(), -65537);
+        assert_eq!(reader.read_i32_le(), -65536);
+        assert_eq!(reader.read_i32_le(), -65536);
+        assert_eq!(reader.read_i16_ne(), i16::MIN);
+        assert_eq!(reader.read_u16_be(), u16::MAX);
+        assert_eq!(reader.read_i16_le(), 16384);
+        assert_eq!(reader.read_u8_ne(), 128);
+        assert_eq!(reader.read_i8_ne(), -127);
+        assert!(reader.byte_array().is_empty());
+
+        let buffer = [0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x80, 0x01, 0x80];
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_q16_16_be(), -32768.0);
+        assert_eq!(reader.read_snorm16_be(), -1.0);
+        assert_eq!(reader.read_snorm16_be(), -1.0);
+        assert_eq!(reader.read_snorm8(), -1.0);
+    }
+
+    #[test]
+    fn java_data() {
+        // As written by DataOutputStream.writeUTF("A\u0000\u00e9\u20ac\ud83e\udd80")
+        let expected = [
+            0x00, 0x0E, 0x41, 0xC0, 0x80, 0xC3, 0xA9, 0xE2, 0x82, 0xAC, 0xED, 0xA0, 0xBE, 0xED, 0xB6, 0x80,
+        ];
+        let mut writer = FastByteWriter::new();
+        writer.write_java_utf("A\0é€🦀").unwrap();
+        assert_eq!(writer.as_slice(), &expected);
+
+        let mut reader = FastByteReader::new(&expected);
+        assert_eq!(reader.read_java_utf().unwrap(), "A\0é€🦀");
+
+        let mut writer = FastByteWriter::new();
+        writer.write_java_utf("").unwrap();
+        writer.write_java_char('é').unwrap();
+        writer.write_java_boolean(true);
+        assert_eq!(writer.as_slice(), &[0x00, 0x00, 0x00, 0xE9, 0x01]);
+        assert!(matches!(writer.write_java_char('🦀'), Err(ByteError::OutOfRange)));
+        let too_long = "é".repeat(32768);
+        assert!(matches!(
+            writer.write_java_utf(&too_long),
+            Err(ByteError::TooLong { length: 65536, maximum: 65535 })
+        ));
+        assert_eq!(writer.as_slice().len(), 5);
+
+        let mut reader = FastByteReader::new(&[0x00, 0x02, 0x41, 0x42, 0xD8, 0x3E, 0x02]);
+        assert_eq!(reader.read_java_utf().unwrap(), "AB");
+        assert!(matches!(reader.read_java_char(), Err(ByteError::InvalidUtf16 { unit: 0xD83E })));
+        assert!(reader.read_java_boolean());
+
+        // A truncated sequence, a stray continuation byte, a 4-byte UTF-8 sequence and a lone surrogate
+        let mut reader = FastByteReader::new(&[
+            0x00, 0x02, 0x41, 0xC3,
+            0x00, 0x01, 0x80,
+            0x00, 0x04, 0xF0, 0x9F, 0xA6, 0x80,
+            0x00, 0x03, 0xED, 0xA0, 0xBE,
+            0x00, 0x01, 0x41,
+        ]);
+        assert!(matches!(reader.read_java_utf(), Err(ByteError::InvalidUtf8 { position: 1 })));
+        assert!(matches!(reader.read_java_utf(), Err(ByteError::InvalidUtf8 { position: 0 })));
+        assert!(matches!(reader.read_java_utf(), Err(ByteError::InvalidUtf8 { position: 0 })));
+        assert!(matches!(reader.read_java_utf(), Err(ByteError::InvalidUtf16 { unit: 0xD83E })));
+        assert_eq!(reader.read_java_utf().unwrap(), "A");
+    }
+
+    #[test]
+    fn dotnet_binary() {
+        // As written by BinaryWriter: Write("hello"), Write7BitEncodedInt(300), Write7BitEncodedInt(-1),
+        // Write('€'), Write(123.45m), Write(-1m) and Write7BitEncodedInt64(long.MinValue)
+        let expected = [
+            0x05, 0x68, 0x65, 0x6C, 0x6C, 0x6F,
+            0xAC, 0x02,
+            0xFF, 0xFF, 0xFF, 0xFF, 0x0F,
+            0xE2, 0x82, 0xAC,
+            0x39, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01,
+        ];
+        let price = Decimal { mantissa: 12345, scale: 2, negative: false };
+        let minus_one = Decimal { mantissa: 1, scale: 0, negative: true };
+
+        let mut writer = FastByteWriter::new();
+        writer.write_dotnet_string("hello").unwrap();
+        writer.write_7bit_encoded_int(300);
+        writer.write_7bit_encoded_int(-1);
+        writer.write_dotnet_char('€').unwrap();
+        writer.write_dotnet_decimal(price).unwrap();
+        writer.write_dotnet_decimal(minus_one).unwrap();
+        writer.write_7bit_encoded_int64(i64::MIN);
+        assert_eq!(writer.as_slice(), &expected);
+
+        assert!(matches!(writer.write_dotnet_char('🦀'), Err(ByteError::OutOfRange)));
+        assert!(matches!(
+            writer.write_dotnet_decimal(Decimal { mantissa: 1 << 96, scale: 0, negative: false }),
+            Err(ByteError::OutOfRange)
+        ));
+        assert!(matches!(
+            writer.write_dotnet_decimal(Decimal { mantissa: 1, scale: 29, negative: false }),
+            Err(ByteError::OutOfRange)
+        ));
+        assert_eq!(writer.as_slice().len(), expected.len());
+
+        let mut reader = FastByteReader::new(&expected);
+        assert_eq!(reader.read_dotnet_string().unwrap(), "hello");
+        assert_eq!(reader.read_7bit_encoded_int().unwrap(), 300);
+        assert_eq!(reader.read_7bit_encoded_int().unwrap(), -1);
+        assert_eq!(reader.read_dotnet_char().unwrap(), '€');
+        assert_eq!(reader.read_dotnet_decimal().unwrap(), price);
+        assert_eq!(reader.read_dotnet_decimal().unwrap(), minus_one);
+        assert_eq!(reader.read_7bit_encoded_int64().unwrap(), i64::MIN);
+
+        let mut reader = FastByteReader::new(&[
+            0xFF, 0xFF, 0xFF, 0xFF, 0x10,
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02,
+            0x02, 0xC3, 0x28,
+            0xFF, 0xFF, 0xFF, 0xFF, 0x0F,
+        ]);
+        assert!(matches!(reader.read_7bit_encoded_int(), Err(ByteError::OutOfRange)));
+        assert!(matches!(reader.read_7bit_encoded_int64(), Err(ByteError::OutOfRange)));
+        assert!(matches!(reader.read_dotnet_string(), Err(ByteError::InvalidUtf8 { position: 0 })));
+        assert!(matches!(reader.read_dotnet_string(), Err(ByteError::OutOfRange)));
+
+        let mut reader = FastByteReader::new(&[0xF0, 0x9F, 0xA6, 0x80]);
+        assert!(matches!(reader.read_dotnet_char(), Err(ByteError::InvalidUtf8 { position: 0 })));
+
+        let mut flags = [0u8; 16];
+        flags[14] = 29;
+        assert!(matches!(FastByteReader::new(&flags).read_dotnet_decimal(), Err(ByteError::OutOfRange)));
+        flags[14] = 0;
+        flags[12] = 1;
+        assert!(matches!(FastByteReader::new(&flags).read_dotnet_decimal(), Err(ByteError::OutOfRange)));
+    }
+
+    #[test]
+    fn integer_widths() {
+        let mut writer = FastByteWriter::new();
+        writer.write_uint_be(0x0102_0304_0506, 6).unwrap();
+        writer.write_uint_le(0x0102_0304_0506, 6).unwrap();
+        writer.write_int_be(-2, 3).unwrap();
+        writer.write_int_le(-129, 2).unwrap();
+        writer.write_uint_be(0, 0).unwrap();
+        writer.write_uint128_be(u128::MAX >> 8, 15).unwrap();
+        writer.write_int128_le(i128::MIN, 16).unwrap();
+        assert_eq!(
+            &writer.as_slice()[..17],
+            &[1, 2, 3, 4, 5, 6, 6, 5, 4, 3, 2, 1, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF]
+        );
+
+        assert!(matches!(writer.write_uint_be(0, 9), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_uint_le(256, 1), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_int_be(128, 1), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_int_le(-129, 1), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_int_ne(1, 0), Err(ByteError::OutOfRange)));
+        assert!(matches!(writer.write_uint128_be(0, 17), Err(ByteError::OutOfRange)));
+        assert_eq!(writer.as_slice().len(), 17 + 15 + 16);
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(reader.read_uint_be(6).unwrap(), 0x0102_0304_0506);
+        assert_eq!(reader.read_uint_le(6).unwrap(), 0x0102_0304_0506);
+        assert_eq!(reader.read_int_be(3).unwrap(), -2);
+        assert_eq!(reader.read_int_le(2).unwrap(), -129);
+        assert_eq!(reader.read_uint_be(0).unwrap(), 0);
+        assert_eq!(reader.read_uint128_be(15).unwrap(), u128::MAX >> 8);
+        assert!(matches!(reader.read_int128_le(17), Err(ByteError::OutOfRange)));
+        assert_eq!(reader.read_int128_le(16).unwrap(), i128::MIN);
+
+        let buffer = [0x80, 0x00, 0x00, 0x7F, 0xFF];
+        let mut reader = FastByteReader::new(&buffer);
+        assert!(matches!(reader.read_int_ne(9), Err(ByteError::OutOfRange)));
+        assert_eq!(reader.read_int_be(3).unwrap(), -0x80_0000);
+        assert_eq!(reader.read_int_le(2).unwrap(), -129);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_structs() {
+        use crate::derive::{HyperRead, HyperWrite};
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "le")]
+        struct Inner(u16, char);
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "ne")]
+        struct Wrapper<T> {
+            value: T,
+            scale: f64,
+        }
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        struct Packet {
+            unsigned8: u8,
+            unsigned128: u128,
+            signed_size: isize,
+            float16: f16,
+            float32: f32,
+            flag: bool,
+            inner: Inner,
+            wrapped: Wrapper<Inner>,
+        }
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        struct Empty;
+
+        let packet = Packet {
+            unsigned8: 7,
+            unsigned128: u128::MAX - 1,
+            signed_size: -3,
+            float16: f16::from_f32(1.5),
+            float32: 2.25,
+            flag: true,
+            inner: Inner(0x0102, 'é'),
+            wrapped: Wrapper { value: Inner(3, 'x'), scale: 0.5 },
+        };
+
+        let mut writer = FastByteWriter::new();
+        packet.hyper_write(&mut writer).unwrap();
+        Empty.hyper_write(&mut writer).unwrap();
+
+        let mut expected = FastByteWriter::new();
+        expected.write_u8_be(7);
+        expected.write_u128_be(u128::MAX - 1);
+        expected.write_isize_be(-3);
+        expected.write_f16_be(f16::from_f32(1.5));
+        expected.write_f32_be(2.25);
+        expected.write_bool(true);
+        expected.write_u16_le(0x0102);
+        expected.write_char_le('é');
+        expected.write_u16_le(3);
+        expected.write_char_le('x');
+        expected.write_f64_ne(0.5);
+        assert_eq!(writer.as_slice(), expected.as_slice());
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(Packet::hyper_read(&mut reader).unwrap(), packet);
+        assert_eq!(Empty::hyper_read(&mut reader).unwrap(), Empty);
+        assert!(reader.byte_array().is_empty());
+
+        let mut invalid = FastByteWriter::new();
+        invalid.write_u16_le(1);
+        invalid.write_u32_le(0xD800);
+        let mut reader = FastByteReader::new(&invalid);
+        assert!(matches!(Inner::hyper_read(&mut reader), Err(ByteError::InvalidChar { value: 0xD800 })));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_collections() {
+        use crate::derive::{HyperRead, HyperWrite};
+        use alloc::string::String;
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "be")]
+        struct Inner(u16);
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "le")]
+        struct Collections {
+            name: String,
+            values: Vec<u16>,
+            pair: [i16; 2],
+            nested: Vec<Vec<u8>>,
+            checksum: Option<u32>,
+            inner: Option<Inner>,
+            inners: [Inner; 2],
+        }
+
+        let collections = Collections {
+            name: String::from("hi"),
+            values: vec![1, 0x0203],
+            pair: [-1, 4],
+            nested: vec![vec![5], vec![]],
+            checksum: Some(6),
+            inner: None,
+            inners: [Inner(7), Inner(8)],
+        };
+
+        let mut writer = FastByteWriter::new();
+        collections.hyper_write(&mut writer).unwrap();
+
+        // Stored the same way as with the codec traits
+        let mut expected = FastByteWriter::new();
+        let Collections { name, values, pair, nested, checksum, .. } = &collections;
+        expected.encode_le(name).unwrap();
+        expected.encode_le(values).unwrap();
+        expected.encode_le(pair).unwrap();
+        expected.encode_le(nested).unwrap();
+        expected.encode_le(checksum).unwrap();
+        expected.write_bool(false);
+        expected.write_u16_be(7);
+        expected.write_u16_be(8);
+        assert_eq!(writer.as_slice(), expected.as_slice());
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(Collections::hyper_read(&mut reader).unwrap(), collections);
+        assert!(reader.byte_array().is_empty());
+
+        let mut reader = FastByteReader::new(&[1, 0, 0, 0, 0xFF]);
+        assert!(matches!(Collections::hyper_read(&mut reader), Err(ByteError::InvalidUtf8 { position: 0 })));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_field_attributes() {
+        use crate::derive::{HyperRead, HyperWrite};
+        use alloc::string::String;
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "le")]
+        struct Record {
+            #[hyper(magic = b"HB")]
+            magic: [u8; 2],
+            name_length: u8,
+            #[hyper(len = "name_length")]
+            name: String,
+            #[hyper(be)]
+            flags: u16,
+            #[hyper(if = "flags & 0x8000 != 0", len = "name_length as usize * 2")]
+            extra: Option<Vec<u8>>,
+            #[hyper(pad = 2)]
+            tail: u32,
+        }
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        struct Pair(#[hyper(le)] u16, #[hyper(skip)] u8, #[hyper(pad = 1)] u16);
+
+        let record = Record {
+            magic: *b"HB",
+            name_length: 2,
+            name: String::from("hb"),
+            flags: 0x8001,
+            extra: Some(vec![1, 2, 3, 4]),
+            tail: 5,
+        };
+        let mut writer = FastByteWriter::new();
+        record.hyper_write(&mut writer).unwrap();
+        Pair(1, 9, 2).hyper_write(&mut writer).unwrap();
+        assert_eq!(
+            writer.as_slice(),
+            b"HB\x02hb\x80\x01\x01\x02\x03\x04\x00\x00\x05\x00\x00\x00\x01\x00\x00\x00\x02"
+        );
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(Record::hyper_read(&mut reader).unwrap(), record);
+        assert_eq!(Pair::hyper_read(&mut reader).unwrap(), Pair(1, 0, 2));
+
+        let mut writer = FastByteWriter::new();
+        let wrong_length = Record { name_length: 3, ..record };
+        assert!(matches!(
+            wrong_length.hyper_write(&mut writer),
+            Err(ByteError::LengthMismatch { expected: 3, actual: 2 })
+        ));
+        let missing = Record { name_length: 2, extra: None, ..wrong_length };
+        assert!(matches!(missing.hyper_write(&mut writer), Err(ByteError::ConditionMismatch)));
+        let absent = Record { flags: 1, ..missing };
+        writer.as_mut_vec().clear();
+        absent.hyper_write(&mut writer).unwrap();
+        assert_eq!(Record::hyper_read(&mut FastByteReader::new(&writer)).unwrap(), absent);
+
+        let mut reader = FastByteReader::new(b"HX\x00\x00\x00");
+        assert!(matches!(Record::hyper_read(&mut reader), Err(ByteError::InvalidMagic)));
+        let mut reader = FastByteReader::new(b"HB\x01\xFF");
+        assert!(matches!(Record::hyper_read(&mut reader), Err(ByteError::InvalidUtf8 { position: 0 })));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_enums() {
+        use crate::derive::{HyperRead, HyperWrite};
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        enum Opcode {
+            Nop,
+            Load = 5,
+            Store,
+        }
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "le", tag_type = "varint")]
+        enum Frame {
+            #[hyper(tag = 300)]
+            Data { opcode: Opcode, #[hyper(be)] value: u32 },
+            #[hyper(tag = 2)]
+            Pair(u8, u8),
+        }
+
+        let mut writer = FastByteWriter::new();
+        Opcode::Nop.hyper_write(&mut writer).unwrap();
+        Opcode::Store.hyper_write(&mut writer).unwrap();
+        Frame::Data { opcode: Opcode::Load, value: 1 }.hyper_write(&mut writer).unwrap();
+        Frame::Pair(3, 4).hyper_write(&mut writer).unwrap();
+        assert_eq!(writer.as_slice(), &[0, 6, 0xAC, 0x02, 5, 0, 0, 0, 1, 2, 3, 4]);
+
+        let mut reader = FastByteReader::new(&writer);
+        assert_eq!(Opcode::hyper_read(&mut reader).unwrap(), Opcode::Nop);
+        assert_eq!(Opcode::hyper_read(&mut reader).unwrap(), Opcode::Store);
+        assert_eq!(Frame::hyper_read(&mut reader).unwrap(), Frame::Data { opcode: Opcode::Load, value: 1 });
+        assert_eq!(Frame::hyper_read(&mut reader).unwrap(), Frame::Pair(3, 4));
+
+        let mut reader = FastByteReader::new(&[7, 0xFF, 0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02]);
+        assert!(matches!(Opcode::hyper_read(&mut reader), Err(ByteError::UnknownTag { tag: 7 })));
+        assert!(matches!(Frame::hyper_read(&mut reader), Err(ByteError::UnknownTag { tag: 255 })));
+        assert!(matches!(Frame::hyper_read(&mut reader), Err(ByteError::OutOfRange)));
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        enum Message {
+            #[hyper(tag = 1)]
+            Ping,
+            #[hyper(unknown)]
+            Unknown(u8, Vec<u8>),
+        }
+
+        // An unknown message takes every remaining byte, including the messages after it
+        let mut reader = FastByteReader::new(&[9, 5, 5, 1]);
+        assert_eq!(Message::hyper_read(&mut reader).unwrap(), Message::Unknown(9, vec![5, 5, 1]));
+        assert!(reader.byte_array().is_empty());
+
+        // So each message is read from its own frame, here with its length first
+        let mut reader = FastByteReader::new(&[3, 9, 5, 5, 1, 1]);
+        let mut messages = Vec::new();
+        while !reader.byte_array().is_empty() {
+            let length = reader.read_u8_be() as usize;
+            let frame = reader.read_n_be(length);
+            messages.push(Message::hyper_read(&mut FastByteReader::new(&frame)).unwrap());
+        }
+        assert_eq!(messages, [Message::Unknown(9, vec![5, 5]), Message::Ping]);
+    }
+
+    #[test]
+    fn codec_types() {
+        use alloc::boxed::Box;
+        use alloc::collections::BTreeMap;
+        use alloc::string::String;
+        use crate::codec::{U8Prefix, VarintPrefix};
+        use crate::endian::{EndianReader, EndianWriter, LittleEndian};
+        use crate::reader::LittleReader;
+
+        type Record = (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, f64);
+        type Nested = ([u16; 3], Vec<String>, Option<Box<char>>, Option<bool>, BTreeMap<u8, Vec<bool>>);
+        let record: Record = (1, 2, 3, 4, 5, 6, -1, -2, -3, -4, -5, -2.5);
+        let nested: Nested = (
+            [7, 8, 9],
+            vec![String::from("a"), String::from("bc")],
+            Some(Box::new('x')),
+            None,
+            BTreeMap::from([(1, vec![true]), (2, vec![])]),
+        );
+
+        let mut writer = FastByteWriter::new();
+        writer.encode_be(&record).unwrap();
+        writer.encode_be(&nested).unwrap();
+        EndianWriter::<LittleEndian>::encode(&mut writer, &record).unwrap();
+        EndianWriter::<LittleEndian>::encode_with::<_, VarintPrefix>(&mut writer, &nested).unwrap();
+        let buffer = writer.to_vec();
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.decode_be::<Record>().unwrap(), record);
+        assert_eq!(reader.decode_be::<Nested>().unwrap(), nested);
+        let mut reader = LittleReader::new(reader.byte_array());
+        assert_eq!(reader.decode::<Record>().unwrap(), record);
+        assert_eq!(reader.decode_with::<Nested, VarintPrefix>().unwrap(), nested);
+        assert!(reader.byte_array().is_empty());
+
+        // Lengths
+        let mut writer = FastByteWriter::new();
+        writer.encode_le("hi").unwrap();
+        EndianWriter::<LittleEndian>::encode_with::<_, U8Prefix>(&mut writer, "hi").unwrap();
+        EndianWriter::<LittleEndian>::encode_with::<_, VarintPrefix>(&mut writer, &vec![0u8; 300]).unwrap();
+        assert_eq!(writer.as_slice()[..9], [2, 0, 0, 0, b'h', b'i', 2, b'h', b'i']);
+        assert_eq!(writer.as_slice()[9..11], [0xAC, 0x02]);
+        assert!(matches!(
+            EndianWriter::<LittleEndian>::encode_with::<_, U8Prefix>(&mut writer, &vec![0u8; 256]),
+            Err(ByteError::TooLong { length: 256, maximum: 255 })
+        ));
+
+        // Errors
+        let mut reader = FastByteReader::new(&[2, 0, 0, 0, 0xFF, 0xFE, 2]);
+        assert!(matches!(reader.decode_le::<String>(), Err(ByteError::InvalidUtf8 { position: 0 })));
+        assert!(matches!(reader.decode_le::<Option<u8>>(), Err(ByteError::InvalidBool { value: 2 })));
+        let mut reader = FastByteReader::new(&[0, 0, 0xD8, 0]);
+        assert!(matches!(reader.decode_be::<char>(), Err(ByteError::InvalidChar { value: 0xD800 })));
+        // The strings decoded before the error are dropped
+        let mut reader = FastByteReader::new(&[1, b'a', 1, 0xFF]);
+        assert!(matches!(EndianReader::<LittleEndian>::decode_with::<[String; 3], U8Prefix>(&mut reader), Err(ByteError::InvalidUtf8 { position: 0 })));
+
+        #[cfg(feature = "std")]
+        {
+            use std::collections::HashMap;
+
+            let map = HashMap::from([(String::from("one"), 1i32), (String::from("two"), 2)]);
+            let mut writer = FastByteWriter::new();
+            writer.encode_ne(&map).unwrap();
+            let buffer = writer.to_vec();
+            let mut reader = FastByteReader::new(&buffer);
+            assert_eq!(reader.decode_ne::<HashMap<String, i32>>().unwrap(), map);
+        }
+
+        #[cfg(feature = "half")]
+        {
+            use crate::codec::HyperDecode;
+
+            let mut writer = FastByteWriter::new();
+            writer.encode_be(&[f16::from_f32(1.5), f16::from_f32(-2.0)]).unwrap();
+            let buffer = writer.to_vec();
+            assert_eq!(buffer, [0x3E, 0x00, 0xC0, 0x00]);
+            let mut reader = FastByteReader::new(&buffer);
+            assert_eq!(<[f16; 2] as HyperDecode<crate::endian::BigEndian>>::decode(&mut reader).unwrap()[1], f16::from_f32(-2.0));
+        }
+    }
+
+    #[test]
+    fn size_counter() {
+        use alloc::string::String;
+        use crate::codec::{HyperEncode, U8Prefix};
+        use crate::endian::{EndianWriter, LittleEndian};
+        use crate::writer::SizeCounter;
+
+        fn write_all<W: BigEndianByteWriter + LittleEndianByteWriter + NativeEndianByteWriter>(writer: &mut W) {
+            writer.write_u8_be(1);
+            writer.write_bool(true);
+            writer.write_u64_le(2);
+            writer.write_i128_ne(-3);
+            writer.write_bytes_be(&[0; 100]);
+            writer.write_cstr("hyper").unwrap();
+            writer.write_varint_u64(u64::MAX);
+            writer.write_varint_i64(-1);
+            writer.write_uint_be(0x01_0203_0405, 5).unwrap();
+            writer.write_unorm8(0.5, FixedMode::SATURATING).unwrap();
+            writer.write_java_utf("\u{1F600}").unwrap();
+            writer.write_dotnet_string("hyper").unwrap();
+            writer.write_option(&Some(7u16), |writer, value| writer.write_u16_be(*value));
+            writer.write_f32_le(1.5);
+            writer.write_i8_le(-4);
+            writer.write_result(&Ok::<u8, u8>(5), |writer, value| writer.write_u8_be(*value), |_, _| {});
+            writer.put_zeros(3);
+        }
+
+        let mut counter = SizeCounter::new();
+        assert!(counter.is_empty());
+        write_all(&mut counter);
+        let mut writer = FastByteWriter::new();
+        write_all(&mut writer);
+        assert_eq!(counter.len(), writer.len());
+        assert!(counter.to_vec().is_empty());
+
+        let message = (vec![String::from("a"), String::from("bcd")], Some(-1i64), [1.5f64; 2]);
+        let mut writer = FastByteWriter::new();
+        writer.encode_le(&message).unwrap();
+        assert_eq!(HyperEncode::<LittleEndian>::encoded_len(&message).unwrap(), writer.len());
+        assert_eq!(HyperEncode::<LittleEndian, U8Prefix>::encoded_len(&message).unwrap(), writer.len() - 9);
+
+        let mut counter = SizeCounter::new();
+        counter.write_u32_be(1);
+        counter.clear();
+        assert!(matches!(
+            EndianWriter::<LittleEndian>::encode_with::<_, U8Prefix>(&mut counter, &vec![0u8; 256]),
+            Err(ByteError::TooLong { length: 256, maximum: 255 })
+        ));
+        assert_eq!(counter.len(), 0);
+    }
+
+    #[test]
+    fn views() {
+        use crate::view::{view, view_mut, view_prefix, view_slice, view_slice_mut, View};
+        use crate::wire::{F64Be, I128Le, I32Le, U16Be, U64Le};
+
+        #[repr(C)]
+        struct Entry {
+            kind: u8,
+            offset: U64Le,
+            delta: I32Le,
+        }
+
+        #[repr(C)]
+        struct Index {
+            magic: [u8; 2],
+            count: U16Be,
+            scale: F64Be,
+            total: I128Le,
+        }
+
+        // SAFETY: Both are `#[repr(C)]` with every field a `View`
+        unsafe impl View for Entry {}
+        unsafe impl View for Index {}
+
+        let mut writer = FastByteWriter::new();
+        writer.write_bytes_be(b"IX");
+        writer.write_u16_be(2);
+        writer.write_f64_be(0.25);
+        writer.write_i128_le(-9);
+        for (kind, offset, delta) in [(1u8, 100u64, -1i32), (2, 200, 1)] {
+            writer.write_u8_be(kind);
+            writer.write_u64_le(offset);
+            writer.write_i32_le(delta);
+        }
+        let mut buffer = writer.to_vec();
+
+        let (index, rest) = view_prefix::<Index>(&buffer).unwrap();
+        assert_eq!(core::mem::size_of::<Index>(), 28);
+        assert_eq!(index.magic, *b"IX");
+        assert_eq!(index.count.get(), 2);
+        assert_eq!(index.scale.get(), 0.25);
+        assert_eq!(index.total.get(), -9);
+        let entries = view_slice::<Entry>(rest).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].kind, entries[0].offset.get(), entries[0].delta.get()), (1, 100, -1));
+        assert_eq!((entries[1].kind, entries[1].offset.get(), entries[1].delta.get()), (2, 200, 1));
+
+        // Writes through a view land in the bytes
+        view_mut::<Index>(&mut buffer[..28]).unwrap().count.set(1);
+        for entry in view_slice_mut::<Entry>(&mut buffer[28..]).unwrap() {
+            entry.offset.set(entry.offset.get() + 1);
+        }
+        let mut reader = FastByteReader::new(&buffer[2..]);
+        assert_eq!(reader.read_u16_be(), 1);
+        reader.skip_n(24 + 1);
+        assert_eq!(reader.read_u64_le(), 101);
+
+        // Errors
+        assert!(matches!(view::<Index>(&buffer), Err(ByteError::LengthMismatch { expected: 28, actual: 54 })));
+        assert!(matches!(view_prefix::<Index>(&buffer[..27]), Err(ByteError::LengthMismatch { expected: 28, actual: 27 })));
+        assert!(matches!(view_slice::<Entry>(&buffer[28..53]), Err(ByteError::LengthMismatch { expected: 13, actual: 25 })));
+        assert!(view_slice::<Entry>(&[]).unwrap().is_empty());
+        assert!(view_slice::<[u8; 0]>(&[]).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_views() {
+        use crate::view::{view_slice, View};
+        use crate::wire::{U16Le, U32Be};
+
+        #[derive(View)]
+        #[repr(C)]
+        struct Pixel([u8; 3]);
+
+        #[derive(View)]
+        #[repr(C, packed)]
+        struct Tile {
+            id: U32Be,
+            palette: [U16Le; 2],
+            pixels: [Pixel; 2],
+        }
+
+        let bytes = [0, 0, 0, 1, 2, 0, 3, 0, 1, 2, 3, 4, 5, 6];
+        let tiles = view_slice::<Tile>(&bytes).unwrap();
+        assert_eq!(tiles.len(), 1);
+        assert_eq!(tiles[0].id.get(), 1);
+        assert_eq!(tiles[0].palette.map(|color| color.get()), [2, 3]);
+        assert_eq!(tiles[0].pixels[1].0, [4, 5, 6]);
+    }
+
+    #[test]
+    fn wire_values() {
+        use alloc::collections::BTreeSet;
+        use alloc::format;
+        use crate::wire::{F32Le, I16Be, I64Le, U16Be, U32Le, U128Be};
+
+        let small = U32Le::new(7);
+        let large = U32Le::from(0x0100_0000);
+        // Compared on the value, where the bytes alone would order them the other way around
+        assert!(small < large);
+        assert_eq!(small.to_bytes(), [7, 0, 0, 0]);
+        assert_eq!(u32::from(large), 0x0100_0000);
+        assert_eq!(format!("{small:?} {large} {:x}", large.get()), "7 16777216 1000000");
+        assert_eq!(BTreeSet::from([large, small, U32Le::new(7)]).len(), 2);
+
+        assert_eq!(I16Be::new(-300).checked_add(I16Be::new(100)), Some(I16Be::new(-200)));
+        assert_eq!(I16Be::new(i16::MAX).checked_add(I16Be::new(1)), None);
+        assert_eq!(U16Be::new(3).checked_sub(U16Be::new(4)), None);
+        assert_eq!(I64Le::new(6).checked_mul(I64Le::new(-7)), Some(I64Le::new(-42)));
+        assert_eq!(U128Be::new(7).checked_div(U128Be::new(0)), None);
+        assert_eq!(U128Be::new(7).checked_rem(U128Be::new(4)), Some(U128Be::new(3)));
+        assert!(F32Le::new(f32::NAN) != F32Le::new(f32::NAN));
+        assert!(F32Le::new(-1.0) < F32Le::new(0.5));
+
+        // Written and read as they are, whichever endian the writer is in
+        let mut writer = FastByteWriter::new();
+        writer.write_wire(&U16Be::new(0x0102));
+        writer.encode_le(&(U16Be::new(0x0304), small)).unwrap();
+        writer.write_u16_le(0x0506);
+        let buffer = writer.to_vec();
+        assert_eq!(buffer, [1, 2, 3, 4, 7, 0, 0, 0, 6, 5]);
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(reader.read_wire::<U16Be>().get(), 0x0102);
+        assert_eq!(reader.decode_be::<(U16Be, U32Le)>().unwrap(), (U16Be::new(0x0304), small));
+        assert_eq!(reader.read_wire::<U16Be>(), U16Be::from_bytes([6, 5]));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_wire_fields() {
+        use crate::derive::{HyperRead, HyperWrite};
+        use crate::wire::{I32Le, U16Be};
+
+        #[derive(HyperRead, HyperWrite, PartialEq, Debug)]
+        #[hyper(endian = "le")]
+        struct Packet {
+            id: U16Be,
+            delta: I32Le,
+            count: u16,
+        }
+
+        let packet = Packet { id: U16Be::new(1), delta: I32Le::new(-2), count: 3 };
+        let mut writer = FastByteWriter::new();
+        packet.hyper_write(&mut writer).unwrap();
+        let buffer = writer.to_vec();
+        assert_eq!(buffer, [0, 1, 0xFE, 0xFF, 0xFF, 0xFF, 3, 0]);
+        assert_eq!(Packet::hyper_read(&mut FastByteReader::new(&buffer)).unwrap(), packet);
+    }
+
+    #[test]
+    fn versioned_sections() {
+        use alloc::string::String;
+        use crate::endian::LittleEndian;
+        use crate::error::ByteError;
+        use crate::versioned::{read_header, write_versioned, Versions};
+
+        let versions = Versions::new()
+            .register_decode::<LittleEndian, u16>(2, |id| (id, String::new()))
+            .register_decode::<LittleEndian, (u16, String)>(4, |pair| pair)
+            .register(5, |bytes| Ok((bytes.len() as u16, String::from("raw"))));
+        assert_eq!(versions.versions().collect::<Vec<_>>(), [2, 4, 5]);
+
+        let mut writer = FastByteWriter::new();
+        write_versioned::<LittleEndian, _, _>(&mut writer, 3, &(9u16, 1u8)).unwrap();
+        write_versioned::<LittleEndian, _, _>(&mut writer, 4, &(10u16, String::from("four"))).unwrap();
+        write_versioned::<LittleEndian, _, _>(&mut writer, 1, &11u16).unwrap();
+        write_versioned::<LittleEndian, _, _>(&mut writer, 7, &[0u8; 6]).unwrap();
+        writer.write_u8_le(0xAA);
+        let buffer = writer.to_vec();
+        assert_eq!(buffer[..9], [3, 0, 3, 0, 0, 0, 9, 0, 1]);
+
+        let mut reader = FastByteReader::new(&buffer);
+        // Version 3 falls back to the version 2 decoder, which skips the trailing byte
+        assert_eq!(versions.read::<LittleEndian, _>(&mut reader).unwrap(), (9, String::new()));
+        assert_eq!(versions.read::<LittleEndian, _>(&mut reader).unwrap(), (10, String::from("four")));
+        // Older than every decoder, yet still skipped over
+        assert!(matches!(versions.read::<LittleEndian, _>(&mut reader), Err(ByteError::UnknownTag { tag: 1 })));
+        assert_eq!(versions.read::<LittleEndian, _>(&mut reader).unwrap(), (6, String::from("raw")));
+        assert_eq!(reader.read_u8_le(), 0xAA);
+
+        let mut reader = FastByteReader::new(&buffer);
+        assert_eq!(read_header::<LittleEndian, _>(&mut reader).unwrap(), (3, 3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_format() {
+        use alloc::collections::BTreeMap;
+        use alloc::string::String;
+        use ::serde::{Deserialize, Serialize};
+        use crate::reader::{LittleReader, NetworkReader};
+        use crate::serde::{from_reader_be, from_reader_le_with, from_slice_be, from_slice_le, from_slice_le_with, to_vec_be, to_vec_le, to_vec_le_with, to_writer_be, to_writer_le_with, Config, IntEncoding, LengthEncoding};
+        use crate::writer::{LittleWriter, NetworkWriter};
+
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        enum Shape {
+            Empty,
+            Circle(f64),
+            Rectangle { width: u16, height: u16 },
+        }
+
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Scene<'a> {
+            name: String,
+            tag: &'a str,
+            #[serde(with = "serde_bytes_like")]
+            raw: &'a [u8],
+            shapes: Vec<Shape>,
+            scores: BTreeMap<u8, i64>,
+            parent: Option<u32>,
+            marker: char,
+        }
+
+        mod serde_bytes_like {
+            use ::serde::{Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(bytes: &&[u8], serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(bytes)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<&'de [u8], D::Error> {
+                <&[u8] as ::serde::Deserialize>::deserialize(deserializer)
+            }
+        }
+
+        let scene = Scene {
+            name: String::from("level"),
+            tag: "main",
+            raw: &[1, 2, 3],
+            shapes: vec![Shape::Empty, Shape::Circle(0.5), Shape::Rectangle { width: 3, height: 4 }],
+            scores: BTreeMap::from([(1, -5), (2, 300)]),
+            parent: Some(9),
+            marker: '\u{1F600}',
+        };
+
+        let buffer = to_vec_be(&scene).unwrap();
+        let read: Scene = from_slice_be(&buffer).unwrap();
+        assert_eq!(read, scene);
+        // The borrowed fields point into the buffer
+        assert!(buffer.as_ptr_range().contains(&read.tag.as_ptr()));
+        assert!(buffer.as_ptr_range().contains(&read.raw.as_ptr()));
+
+        let little = to_vec_le(&scene).unwrap();
+        assert_ne!(little, buffer);
+        assert_eq!(little.len(), buffer.len());
+        assert_eq!(from_slice_le::<Scene>(&little).unwrap(), scene);
+
+        let config = Config { integers: IntEncoding::Varint, lengths: LengthEncoding::U8 };
+        let compact = to_vec_le_with(&scene, config).unwrap();
+        assert!(compact.len() < little.len());
+        assert_eq!(from_slice_le_with::<Scene>(&compact, config).unwrap(), scene);
+
+        assert_eq!(to_vec_le(&Shape::Rectangle { width: 3, height: 4 }).unwrap(), [2, 0, 0, 0, 3, 0, 4, 0]);
+        assert_eq!(to_vec_le_with(&-3i32, Config::VARINT).unwrap(), [5]);
+
+        // Errors
+        let long = vec![0u8; 256];
+        assert!(matches!(to_vec_le_with(&long, config), Err(ByteError::TooLong { length: 256, maximum: 255 })));
+        assert!(matches!(from_slice_be::<char>(&[0, 0, 0xD8, 0]), Err(ByteError::InvalidChar { value: 0xD800 })));
+        assert!(matches!(from_slice_be::<Shape>(&[0, 0, 0, 7]), Err(ByteError::Custom { .. })));
+        assert!(matches!(from_slice_be::<&str>(&[0, 0, 0, 1, 0xFF]), Err(ByteError::InvalidUtf8 { position: 0 })));
+        assert!(matches!(from_slice_le_with::<u16>(&[0xFF, 0xFF, 0x7F], Config::VARINT), Err(ByteError::OutOfRange)));
+
+        // Writers and readers, which copy instead of borrowing
+        let mut writer = NetworkWriter::new();
+        to_writer_be(&mut writer, &(7u32, String::from("hi"))).unwrap();
+        writer.write_u8_be(9);
+        let buffer = writer.to_vec();
+        let mut reader = NetworkReader::new(&buffer);
+        assert_eq!(from_reader_be::<_, (u32, String)>(&mut reader).unwrap(), (7, String::from("hi")));
+        assert_eq!(reader.read_u8_be(), 9);
+
+        let mut writer = LittleWriter::new();
+        to_writer_le_with(&mut writer, &vec![Shape::Circle(0.5), Shape::Empty], config).unwrap();
+        let buffer = writer.to_vec();
+        let shapes: Vec<Shape> = from_reader_le_with(&mut LittleReader::new(&buffer), config).unwrap();
+        assert_eq!(shapes, [Shape::Circle(0.5), Shape::Empty]);
+
+        // Truncated input
+        let truncated = from_slice_be::<(u32, String)>(&[0, 0, 0, 1, 0, 0, 0, 9, b'a']);
+        assert!(matches!(truncated, Err(ByteError::UnexpectedEnd { needed: 9, remaining: 1 })));
+        assert!(matches!(from_slice_be::<u64>(&[0, 0, 1]), Err(ByteError::UnexpectedEnd { needed: 8, remaining: 3 })));
+        assert!(matches!(from_slice_be::<Option<u8>>(&[1]), Err(ByteError::UnexpectedEnd { needed: 1, remaining: 0 })));
+        let varint = from_slice_le_with::<u32>(&[0x80, 0x80], Config::VARINT);
+        assert!(matches!(varint, Err(ByteError::UnexpectedEnd { needed: 3, remaining: 2 })));
+        let copied = from_reader_be::<_, String>(&mut NetworkReader::new(&[0, 0, 0, 4, b'a']));
+        assert!(matches!(copied, Err(ByteError::UnexpectedEnd { needed: 4, remaining: 1 })));
+    }
+
     // Has bound checks for every indexing operation
     #[inline(always)]
     pub fn read_f64_ne_indexing(bytes: &[u8]) -> f64 {
@@ -2553,6 +3897,331 @@ mod tests {
         println!("Ordinary: {result} - {}s", end.as_secs_f64());
     }
 
+    #[test]
+    fn stream_compaction() {
+        use crate::hyper_stream::LittleStream;
+
+        let mut stream = LittleStream::with_compact_threshold(Vec::new(), 4);
+        stream.write_u32_le(1);
+        stream.write_u32_le(2);
+        assert_eq!(stream.read_u16_le(), 1);
+        assert_eq!(stream.len_unread(), 6);
+        // Only 2 bytes consumed, so they are kept
+        stream.write_u8_le(3);
+        assert_eq!(stream.as_slice().len(), 9);
+
+        stream.skip_n(4);
+        // Past the threshold, so the unread bytes are moved to the front before writing
+        stream.write_u8_le(4);
+        assert_eq!(stream.as_slice(), [0, 0, 3, 4]);
+        assert_eq!(stream.read_u16_le(), 0);
+
+        // Everything read but within the threshold, so the bytes are kept
+        stream.skip_n(2);
+        assert_eq!(stream.len_unread(), 0);
+        stream.write_u32_le(9);
+        assert_eq!(stream.as_slice(), [0, 0, 3, 4, 9, 0, 0, 0]);
+
+        // Everything read and past the threshold, so the next write starts over
+        stream.skip_n(4);
+        stream.write_u16_le(5);
+        assert_eq!(stream.as_slice(), [5, 0]);
+
+        // Without a threshold, every byte written is kept until asked to compact
+        let mut stream = HyperStream::new(Vec::new());
+        stream.write_u64_be(6);
+        stream.write_u8_be(7);
+        assert_eq!(stream.read_u64_be(), 6);
+        stream.write_u8_be(8);
+        assert_eq!(stream.as_slice().len(), 10);
+        stream.compact();
+        assert_eq!(stream.as_slice(), [7, 8]);
+        assert_eq!(stream.compact_threshold(), usize::MAX);
+        stream.clear();
+        assert_eq!(stream.len_unread(), 0);
+    }
+
+    #[test]
+    fn ring_stream() {
+        use crate::hyper_stream::RingStream;
+
+        let mut ring = RingStream::new(16);
+        assert!(ring.is_empty());
+        for round in 0..20u32 {
+            // Writes of 12 bytes against a capacity of 16 land across the wrap point every other round
+            ring.write_u64_le(round as u64);
+            ring.write_u8_le(round as u8);
+            ring.write_bool(round % 2 == 0);
+            ring.write_u16_be(round as u16);
+            assert_eq!(ring.len(), 12);
+            assert_eq!(ring.read_u64_le(), round as u64);
+            assert_eq!(ring.read_u8_le(), round as u8);
+            assert_eq!(ring.read_bool().unwrap(), round % 2 == 0);
+            assert_eq!(ring.read_u16_be(), round as u16);
+        }
+
+        ring.write_u64_be(0x0102_0304_0506_0708);
+        assert_eq!(ring.read_u32_be(), 0x0102_0304);
+        assert_eq!(ring.push_slice(&[9; 20]), 12);
+        assert!(ring.is_full());
+        assert_eq!(ring.free(), 0);
+        assert_eq!(ring.push_slice(&[10]), 0);
+
+        let (front, back) = ring.as_slices();
+        assert_eq!(front.len() + back.len(), 16);
+        assert_eq!(front[..4], [5, 6, 7, 8]);
+
+        let mut bytes = [0; 6];
+        assert_eq!(ring.pop_slice(&mut bytes), 6);
+        assert_eq!(bytes, [5, 6, 7, 8, 9, 9]);
+        let (front, back) = ring.as_slices();
+        assert_eq!([front, back].concat(), [9; 10]);
+        ring.clear();
+        assert_eq!(ring.pop_slice(&mut bytes), 0);
+        assert_eq!(ring.to_vec(), []);
+
+        // A read across the wrap point rotates the ring to start at the front
+        let mut ring = RingStream::new(8);
+        ring.write_u16_be(1);
+        ring.write_u32_be(2);
+        assert_eq!(ring.read_u16_be(), 1);
+        ring.write_u32_be(0x0A0B_0C0D);
+        assert_eq!(ring.as_slices(), (&[0, 0, 0, 2, 0x0A, 0x0B][..], &[0x0C, 0x0D][..]));
+        assert_eq!(ring.read_u32_be(), 2);
+        assert_eq!(ring.as_slices(), (&[0x0A, 0x0B, 0x0C, 0x0D][..], &[][..]));
+        assert_eq!(ring.read_u32_be(), 0x0A0B_0C0D);
+        ring.write_u64_be(3);
+        ring.skip_n(2);
+        ring.write_u16_be(4);
+        // Skipped across the wrap point without assembling the bytes
+        ring.skip_n(7);
+        assert_eq!(ring.as_slices(), (&[4][..], &[][..]));
+
+        // Reads longer than a single value see every unread byte across the wrap point
+        let mut ring = RingStream::new(64);
+        ring.put_bytes(&[1; 50]);
+        ring.skip_n(49);
+        ring.put_bytes(b"a line across the wrap pt\n");
+        ring.skip_n(1);
+        assert_eq!(ring.as_slices().0.len(), 14);
+        assert_eq!(ring.find(b"pt"), Some(23));
+        assert_eq!(ring.read_until(b'\n').unwrap(), b"a line across the wrap pt");
+        ring.put_bytes(&[2; 40]);
+        assert_eq!(ring.read_n_be(32), [2; 32]);
+        assert_eq!(ring.len(), 8);
+
+        // Single bytes written past a value still count against the capacity
+        let mut ring = RingStream::new(8);
+        ring.write_u32_be(1);
+        ring.write_u8_be(2);
+        ring.write_u16_be(3);
+        assert_eq!(ring.free(), 1);
+        ring.write_bool(true);
+        assert!(ring.is_full());
+        assert_eq!(ring.read_u32_be(), 1);
+        assert_eq!(ring.read_u8_be(), 2);
+        assert_eq!(ring.read_u16_be(), 3);
+        assert!(ring.read_bool().unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn buffered_reader() {
+        use crate::extensions::buffered::HyperBufReader;
+        use crate::wire::{U16Be, U32Be};
+        use std::io::{Error, ErrorKind, Read};
+
+        // Hands out a single byte at a time, then fails once the bytes run out
+        struct Trickle(Vec<u8>, usize);
+        impl Read for Trickle {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+                if self.1 == self.0.len() {
+                    return Err(Error::other("connection reset"));
+                }
+                buf[0] = self.0[self.1];
+                self.1 += 1;
+                Ok(1)
+            }
+        }
+
+        let mut writer = FastByteWriter::new();
+        writer.write_u128_be(1);
+        writer.write_f64_le(2.5);
+        writer.write_u32_be(45);
+        writer.write_bytes_be(b"a string longer than the buffer of the reader");
+        writer.write_u16_ne(3);
+        let buffer = writer.to_vec();
+
+        let mut reader = HyperBufReader::with_capacity(16, Trickle(buffer, 0));
+        assert_eq!(reader.capacity(), 16);
+        // The transparent reads pull a byte at a time until they have the whole value
+        assert_eq!(reader.read_u128_be(), 1);
+        assert_eq!(reader.read_f64_le(), 2.5);
+        // Needs more than the buffer holds, so the buffer grows
+        let length = reader.try_read_wire::<U32Be>().unwrap().get() as usize;
+        let string = reader.try_read_n(length).unwrap();
+        assert!(reader.capacity() >= length);
+        assert_eq!(string, b"a string longer than the buffer of the reader");
+        assert!(reader.take_error().is_none());
+        assert_eq!(reader.read_u16_ne(), 3);
+
+        // Pulling past the last value runs into the error, which is kept for later
+        assert_eq!(reader.take_error().unwrap().kind(), ErrorKind::Other);
+        assert!(reader.byte_array().is_empty());
+        assert_eq!(reader.try_read_wire::<u8>().unwrap_err().kind(), ErrorKind::Other);
+        assert_eq!(reader.fill(1).unwrap_err().kind(), ErrorKind::Other);
+        assert!(reader.is_finished().is_err());
+        assert!(HyperBufReader::new([].as_slice()).is_finished().unwrap());
+
+        // A value split across two reads of the inner reader
+        let mut reader = HyperBufReader::new([0u8, 0, 0].chain([0u8, 0, 0, 0, 9].as_slice()));
+        assert_eq!(reader.read_u64_be(), 9);
+        assert!(reader.try_read_n(1).is_err());
+
+        // Hands out a single value and must not be read again, as a blocking socket would wait for more
+        struct Once(Option<Vec<u8>>);
+        impl Read for Once {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+                let bytes = self.0.take().expect("read again after the value arrived");
+                buf[..bytes.len()].copy_from_slice(&bytes);
+                Ok(bytes.len())
+            }
+        }
+
+        let mut reader = HyperBufReader::new(Once(Some(vec![0, 0, 0, 7])));
+        reader.set_single_read(true);
+        assert_eq!(reader.read_u32_be(), 7);
+        let mut reader = HyperBufReader::new(Once(Some(vec![0, 8])));
+        reader.set_single_read(true);
+        assert_eq!(reader.try_read_wire::<U16Be>().unwrap().get(), 8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn buffered_writer() {
+        use crate::extensions::buffered::HyperBufWriter;
+        use std::io::{Error, ErrorKind, Write};
+
+        // Takes at most 5 bytes at a time, and fails while `broken` is set
+        struct Limited {
+            bytes: Vec<u8>,
+            broken: bool,
+        }
+        impl Write for Limited {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+                if self.broken {
+                    return Err(Error::other("disk full"));
+                }
+                let amount = buf.len().min(5);
+                self.bytes.extend_from_slice(&buf[..amount]);
+                Ok(amount)
+            }
+
+            fn flush(&mut self) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        let mut writer = HyperBufWriter::with_capacity(8, Limited { bytes: Vec::new(), broken: false });
+        writer.write_u32_be(1);
+        writer.write_bool(true);
+        assert_eq!(writer.buffered(), 5);
+        writer.write_u32_le(2);
+        assert_eq!(writer.buffered(), 0);
+        assert_eq!(writer.get_ref().bytes, [0, 0, 0, 1, 1, 2, 0, 0, 0]);
+
+        writer.get_mut().broken = true;
+        writer.write_u64_ne(3);
+        // Kept buffered, as the inner writer failed
+        assert_eq!(writer.buffered(), 8);
+        // Later writes are dropped while the error is kept, however many there are
+        for value in 0..1000 {
+            writer.write_u64_be(value);
+        }
+        assert_eq!(writer.buffered(), 8);
+        assert_eq!(writer.flush().unwrap_err().kind(), ErrorKind::Other);
+
+        writer.get_mut().broken = false;
+        writer.flush().unwrap();
+        assert_eq!(writer.buffered(), 0);
+        writer.write_u8_be(4);
+        writer.write_u16_be(5);
+
+        // Taking the error accepts writes again, keeping the bytes buffered before it
+        writer.get_mut().broken = true;
+        writer.write_u64_be(6);
+        writer.write_u8_be(7);
+        assert_eq!(writer.take_error().unwrap().kind(), ErrorKind::Other);
+        assert!(writer.take_error().is_none());
+        writer.get_mut().broken = false;
+        writer.write_u8_be(8);
+        assert_eq!(writer.buffered(), 0);
+        let inner = writer.into_inner().unwrap();
+        assert_eq!(inner.bytes.len(), 29);
+        assert_eq!(inner.bytes[9..17], 3u64.to_ne_bytes());
+        assert_eq!(inner.bytes[17..], [4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 8]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn seeking() {
+        use crate::extensions::buffered::HyperBufReader;
+        use crate::hyper_stream::NetworkStream;
+        use crate::reader::NetworkReader;
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let bytes: Vec<u8> = (0..64).collect();
+        let mut reader = NetworkReader::new(&bytes);
+        reader.skip_n(10);
+        assert_eq!(reader.position(), 10);
+        assert_eq!(reader.seek(SeekFrom::Current(-4)).unwrap(), 6);
+        assert_eq!(reader.read_u8_be(), 6);
+        assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 62);
+        assert_eq!(reader.read_u16_be(), 0x3E3F);
+        assert!(reader.seek(SeekFrom::End(1)).is_err());
+        assert!(reader.seek(SeekFrom::Current(-65)).is_err());
+        // A failed seek leaves the position as it was
+        assert_eq!(reader.stream_position().unwrap(), 64);
+        reader.set_position(0);
+        assert_eq!(reader.read_u32_be(), 0x0001_0203);
+
+        let mut stream = NetworkStream::with_compact_threshold(Vec::new(), 8);
+        stream.write_bytes_be(&bytes[..16]);
+        stream.skip_n(12);
+        stream.write_u8_be(16);
+        // Compacted before the write, yet positions still count the released bytes
+        assert_eq!(stream.as_slice(), [12, 13, 14, 15, 16]);
+        assert_eq!(stream.position(), 12);
+        assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 14);
+        assert_eq!(stream.read_u8_be(), 14);
+        assert_eq!(stream.seek(SeekFrom::End(-5)).unwrap(), 12);
+        assert!(stream.seek(SeekFrom::Start(11)).is_err());
+        stream.set_position(16);
+        assert_eq!(stream.read_u8_be(), 16);
+
+        // Everything read and written to again within the threshold, so the bytes can still be sought back to
+        let mut stream = NetworkStream::new(Vec::new());
+        stream.write_u32_be(1);
+        assert_eq!(stream.read_u32_be(), 1);
+        stream.write_u32_be(2);
+        assert_eq!(stream.seek(SeekFrom::Start(0)).unwrap(), 0);
+        assert_eq!(stream.as_slice(), [0, 0, 0, 1, 0, 0, 0, 2]);
+        assert_eq!(stream.read_u32_be(), 1);
+        stream.set_position(4);
+        assert_eq!(stream.read_u32_be(), 2);
+
+        let mut reader = HyperBufReader::with_capacity(16, Cursor::new(bytes.clone()));
+        assert_eq!(reader.read_u32_be(), 0x0001_0203);
+        // Within the buffer, then past it
+        assert_eq!(reader.seek(SeekFrom::Current(-2)).unwrap(), 2);
+        assert_eq!(reader.read_u16_be(), 0x0203);
+        assert_eq!(reader.seek(SeekFrom::Current(30)).unwrap(), 34);
+        assert_eq!(reader.read_u8_be(), 34);
+        assert_eq!(reader.seek(SeekFrom::Start(60)).unwrap(), 60);
+        assert_eq!(reader.read_u32_be(), 0x3C3D_3E3F);
+        assert!(reader.is_finished().unwrap());
+    }
+
     #[test]
     fn fast_stream_be() {
         let my_struct = MyTestStruct::default();
@@ -1,5 +1,56 @@
+use core::ffi::CStr;
 use core::ptr::slice_from_raw_parts;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind, Seek, SeekFrom};
 use crate::readers::traits::*;
+use crate::search::find_byte;
+
+/// Implements the delimiter based reads of a slice reader, which borrow the bytes from the original slice
+/// rather than copying them
+macro_rules! borrowed_reads {
+    ($name:ident) => {
+        impl<'reader> $name<'reader> {
+            /// Reads every byte up to the first [`delimiter`], advancing the readers past the delimiter</br>
+            /// The delimiter itself is consumed, but not returned. The bytes are borrowed from the original slice,
+            /// so they outlive the reader. Returns [`None`] without advancing if there is no delimiter
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use hyper_byte::reader::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let mut readers = ", stringify!($name), "::new(b\"key=value\");")]
+            /// assert_eq!(readers.read_until(b'='), Some(&b"key"[..]));
+            /// assert_eq!(readers.read_until(b'='), None);
+            /// ```
+            pub fn read_until(&mut self, delimiter: u8) -> Option<&'reader [u8]> {
+                let index = find_byte(self.byte_array, delimiter)?;
+                let bytes = &self.byte_array[..index];
+                self.byte_array = &self.byte_array[index + 1..];
+                Some(bytes)
+            }
+
+            /// Reads a NUL-terminated string, advancing the readers past the terminator</br>
+            /// The string is borrowed from the original slice, so it outlives the reader.
+            /// Returns [`None`] without advancing if there is no terminator
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use hyper_byte::reader::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let mut readers = ", stringify!($name), "::new(b\"hyper\\0byte\");")]
+            /// assert_eq!(readers.read_cstr().unwrap().to_str(), Ok("hyper"));
+            /// assert_eq!(readers.read_cstr(), None);
+            /// ```
+            pub fn read_cstr(&mut self) -> Option<&'reader CStr> {
+                let index = find_byte(self.byte_array, 0)?;
+                let bytes = &self.byte_array[..=index];
+                self.byte_array = &self.byte_array[index + 1..];
+                // SAFETY: The bytes end at the first NUL, so it is the only one inside of them
+                Some(unsafe { CStr::from_bytes_with_nul_unchecked(bytes) })
+            }
+        }
+    };
+}
 
 /// Cheap byte readers, which does not hold your hand. If you mess up, it will panic.
 ///
@@ -14,6 +65,7 @@ use crate::readers::traits::*;
 /// let y = readers.read_f64_ne();
 /// ```
 pub struct FastByteReader<'reader> {
+    source: &'reader [u8],
     byte_array: &'reader [u8],
 }
 
@@ -48,10 +100,56 @@ impl<'reader> FastByteReader<'reader> {
     /// let y = readers.read_f64_ne();
     /// ```
     pub const fn new(byte_array: &'reader [u8]) -> Self {
-        Self { byte_array }
+        Self { source: byte_array, byte_array }
+    }
+
+    /// The bytes which are yet to be read, borrowed for as long as the original slice rather than the reader
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    ///
+    /// let slice = [0u8, 1, 2, 3];
+    /// let mut readers = FastByteReader::new(&slice);
+    /// readers.read_u16_be();
+    /// assert_eq!(readers.remaining(), [2, 3]);
+    /// ```
+    pub const fn remaining(&self) -> &'reader [u8] {
+        self.byte_array
+    }
+
+    /// The amount of bytes read so far, counted from the start of the original slice
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    ///
+    /// let slice = [0u8, 1, 2, 3];
+    /// let mut readers = FastByteReader::new(&slice);
+    /// readers.read_u16_be();
+    /// assert_eq!(readers.position(), 2);
+    ///
+    /// readers.set_position(1);
+    /// assert_eq!(readers.read_u16_be(), u16::from_be_bytes([1, 2]));
+    /// ```
+    pub const fn position(&self) -> usize {
+        self.source.len() - self.byte_array.len()
+    }
+
+    /// Moves to the position in the original slice, so the following reads start from there</br>
+    /// Panics if the position is past the end of the slice
+    pub fn set_position(&mut self, position: usize) {
+        match self.source.get(position..) {
+            Some(byte_array) => self.byte_array = byte_array,
+            None => panic!("Attempted to move to a position outside of the array."),
+        }
     }
 }
 
+borrowed_reads!(FastByteReader);
+
 /// Cheap network-focused (big-endian) byte reader, which does not hold your hand. If you mess up, it will panic.
 ///
 /// # Examples
@@ -65,6 +163,7 @@ impl<'reader> FastByteReader<'reader> {
 /// let y = readers.read_f64_be();
 /// ```
 pub struct NetworkReader<'reader> {
+    source: &'reader [u8],
     byte_array: &'reader [u8],
 }
 
@@ -97,10 +196,40 @@ impl<'reader> NetworkReader<'reader> {
     /// let y = readers.read_f64_be();
     /// ```
     pub const fn new(byte_array: &'reader [u8]) -> Self {
-        Self { byte_array }
+        Self { source: byte_array, byte_array }
+    }
+
+    /// The amount of bytes read so far, counted from the start of the original slice
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    ///
+    /// let slice = [0u8, 1, 2, 3];
+    /// let mut readers = NetworkReader::new(&slice);
+    /// readers.read_u16_be();
+    /// assert_eq!(readers.position(), 2);
+    ///
+    /// readers.set_position(1);
+    /// assert_eq!(readers.read_u16_be(), u16::from_be_bytes([1, 2]));
+    /// ```
+    pub const fn position(&self) -> usize {
+        self.source.len() - self.byte_array.len()
+    }
+
+    /// Moves to the position in the original slice, so the following reads start from there</br>
+    /// Panics if the position is past the end of the slice
+    pub fn set_position(&mut self, position: usize) {
+        match self.source.get(position..) {
+            Some(byte_array) => self.byte_array = byte_array,
+            None => panic!("Attempted to move to a position outside of the array."),
+        }
     }
 }
 
+borrowed_reads!(NetworkReader);
+
 /// Cheap little-endian byte reader, which does not hold your hand. If you mess up, it will panic.
 ///
 /// # Examples
@@ -114,6 +243,7 @@ impl<'reader> NetworkReader<'reader> {
 /// let y = readers.read_f64_be();
 /// ```
 pub struct LittleReader<'reader> {
+    source: &'reader [u8],
     byte_array: &'reader [u8],
 }
 
@@ -146,10 +276,40 @@ impl<'reader> LittleReader<'reader> {
     /// let y = readers.read_f64_le();
     /// ```
     pub const fn new(byte_array: &'reader [u8]) -> Self {
-        Self { byte_array }
+        Self { source: byte_array, byte_array }
+    }
+
+    /// The amount of bytes read so far, counted from the start of the original slice
+    ///
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::LittleReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    ///
+    /// let slice = [0u8, 1, 2, 3];
+    /// let mut readers = LittleReader::new(&slice);
+    /// readers.read_u16_le();
+    /// assert_eq!(readers.position(), 2);
+    ///
+    /// readers.set_position(1);
+    /// assert_eq!(readers.read_u16_le(), u16::from_le_bytes([1, 2]));
+    /// ```
+    pub const fn position(&self) -> usize {
+        self.source.len() - self.byte_array.len()
+    }
+
+    /// Moves to the position in the original slice, so the following reads start from there</br>
+    /// Panics if the position is past the end of the slice
+    pub fn set_position(&mut self, position: usize) {
+        match self.source.get(position..) {
+            Some(byte_array) => self.byte_array = byte_array,
+            None => panic!("Attempted to move to a position outside of the array."),
+        }
     }
 }
 
+borrowed_reads!(LittleReader);
+
 /// Cheap native-endian byte reader, which does not hold your hand. If you mess up, it will panic.
 ///
 /// # Examples
@@ -163,6 +323,7 @@ impl<'reader> LittleReader<'reader> {
 /// let y = readers.read_f64_ne();
 /// ```
 pub struct NativeReader<'reader> {
+    source: &'reader [u8],
     byte_array: &'reader [u8],
 }
 
@@ -195,6 +356,90 @@ impl NativeEndianByteReader for NativeReader<'_> {}
         /// let y = readers.read_f64_le();
         /// ```
         pub const fn new(byte_array: &'reader [u8]) -> Self {
-            Self { byte_array }
+            Self { source: byte_array, byte_array }
         }
+
+        /// The amount of bytes read so far, counted from the start of the original slice
+        ///
+        /// # Examples
+        /// ```
+        /// use hyper_byte::reader::NativeReader;
+        /// use hyper_byte::readers::traits::NativeEndianByteReader;
+        ///
+        /// let slice = [0u8, 1, 2, 3];
+        /// let mut readers = NativeReader::new(&slice);
+        /// readers.read_u16_ne();
+        /// assert_eq!(readers.position(), 2);
+        ///
+        /// readers.set_position(1);
+        /// assert_eq!(readers.read_u16_ne(), u16::from_ne_bytes([1, 2]));
+        /// ```
+        pub const fn position(&self) -> usize {
+            self.source.len() - self.byte_array.len()
+        }
+
+        /// Moves to the position in the original slice, so the following reads start from there</br>
+        /// Panics if the position is past the end of the slice
+        pub fn set_position(&mut self, position: usize) {
+            match self.source.get(position..) {
+                Some(byte_array) => self.byte_array = byte_array,
+                None => panic!("Attempted to move to a position outside of the array."),
+            }
+        }
+    }
+
+borrowed_reads!(NativeReader);
+
+#[cfg(feature = "std")]
+impl Seek for FastByteReader<'_> {
+    /// Seeks within the original slice, where a position past either end results in [`ErrorKind::InvalidInput`]
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+        let position = seek_position(position, self.position(), 0, self.source.len())?;
+        self.set_position(position);
+        Ok(position as u64)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Seek for NetworkReader<'_> {
+    /// Seeks within the original slice, where a position past either end results in [`ErrorKind::InvalidInput`]
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+        let position = seek_position(position, self.position(), 0, self.source.len())?;
+        self.set_position(position);
+        Ok(position as u64)
     }
+}
+
+#[cfg(feature = "std")]
+impl Seek for LittleReader<'_> {
+    /// Seeks within the original slice, where a position past either end results in [`ErrorKind::InvalidInput`]
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+        let position = seek_position(position, self.position(), 0, self.source.len())?;
+        self.set_position(position);
+        Ok(position as u64)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Seek for NativeReader<'_> {
+    /// Seeks within the original slice, where a position past either end results in [`ErrorKind::InvalidInput`]
+    fn seek(&mut self, position: SeekFrom) -> Result<u64, Error> {
+        let position = seek_position(position, self.position(), 0, self.source.len())?;
+        self.set_position(position);
+        Ok(position as u64)
+    }
+}
+
+/// Resolves a seek against the current position, within the positions `first` to `end` which can be moved to
+#[cfg(feature = "std")]
+pub(crate) fn seek_position(position: SeekFrom, current: usize, first: usize, end: usize) -> Result<usize, Error> {
+    let target = match position {
+        SeekFrom::Start(offset) => usize::try_from(offset).ok(),
+        SeekFrom::End(offset) => isize::try_from(offset).ok().and_then(|offset| end.checked_add_signed(offset)),
+        SeekFrom::Current(offset) => isize::try_from(offset).ok().and_then(|offset| current.checked_add_signed(offset)),
+    };
+    match target {
+        Some(target) if (first..=end).contains(&target) => Ok(target),
+        _ => Err(Error::new(ErrorKind::InvalidInput, "Attempted to seek to a position outside of the bytes held.")),
+    }
+}
@@ -1,6 +1,27 @@
+use alloc::ffi::CString;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::mem::size_of;
+use core::char::decode_utf16;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime};
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+use crate::codec::HyperDecode;
+use crate::endian::{BigEndian, EndianReader, LittleEndian, NativeEndian};
+use crate::error::ByteError;
+use crate::view::View;
+#[cfg(feature = "std")]
+use crate::time;
+use crate::search::{find_byte, find_slice};
 
 pub trait ByteReader {
     /// Returns a reference to the current underlying byte-slice.
@@ -107,6 +128,14 @@ pub trait ByteReader {
         self.skip_n(size_of::<f16>());
     }
 
+    #[cfg(feature = "half")]
+    /// Skips a `[bf16]` number of bytes from the byte array, advancing the readers forward</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn skip_bf16(&mut self) {
+        self.skip_n(size_of::<bf16>());
+    }
+
     /// Skips a `[f32]` number of bytes from the byte array, advancing the readers forward</br>
     /// Panics if there is not enough space
     #[inline(always)]
@@ -136,6 +165,217 @@ pub trait ByteReader {
             panic!("Attempted to skip bytes of an array without space in the array.");
         }
     }
+
+    /// Finds the first occurrence of [`pattern`] in the remaining bytes, without advancing the readers</br>
+    /// The returned index is relative to the current position, so `&self.byte_array()[..index]` can be borrowed without copying
+    #[inline(always)]
+    fn find(&mut self, pattern: &[u8]) -> Option<usize> {
+        find_slice(self.byte_array(), pattern)
+    }
+
+    /// Reads every byte up to the first [`delimiter`], advancing the readers past the delimiter</br>
+    /// The delimiter itself is consumed, but not returned. Returns [`None`] without advancing if there is no delimiter.
+    /// The bytes are copied, as a reader may not keep them once advanced past. The readers and streams have their own
+    /// `read_until`, which borrows the bytes instead
+    #[inline(always)]
+    fn read_until_vec(&mut self, delimiter: u8) -> Option<Vec<u8>> {
+        let byte_array = self.byte_array();
+        let index = find_byte(byte_array, delimiter)?;
+        let new_length = byte_array.len() - index - 1;
+        let bytes = byte_array[..index].to_vec();
+        // SAFETY: The delimiter was found within the array, so the advancement is within bounds
+        unsafe {
+            self.advance(index + 1, new_length);
+        }
+        Some(bytes)
+    }
+
+    /// Skips every byte up to and including the first [`delimiter`], returning how many bytes came before it</br>
+    /// Returns [`None`] without advancing if there is no delimiter
+    #[inline(always)]
+    fn skip_until(&mut self, delimiter: u8) -> Option<usize> {
+        let byte_array = self.byte_array();
+        let index = find_byte(byte_array, delimiter)?;
+        let new_length = byte_array.len() - index - 1;
+        // SAFETY: The delimiter was found within the array, so the advancement is within bounds
+        unsafe {
+            self.advance(index + 1, new_length);
+        }
+        Some(index)
+    }
+
+    /// Reads a NUL-terminated string, advancing the readers past the terminator</br>
+    /// Returns [`None`] without advancing if there is no terminator.
+    /// The string is copied, as with [`ByteReader::read_until_vec`]. The readers and streams have their own
+    /// `read_cstr`, which borrows the string instead
+    #[inline(always)]
+    fn read_cstring(&mut self) -> Option<CString> {
+        let bytes = self.read_until_vec(0)?;
+        // SAFETY: The bytes end right before the first NUL, so there cannot be one inside of them
+        Some(unsafe { CString::from_vec_unchecked(bytes) })
+    }
+
+    /// Reads a [`bool`] stored as a single byte, advancing the readers forward by 1 byte</br>
+    /// Anything other than `0` or `1` results in an error, though the byte is still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bool(&mut self) -> Result<bool, ByteError> {
+        match read_byte(self) {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(ByteError::InvalidBool { value }),
+        }
+    }
+
+    /// Reads an [`Option`] as a one byte presence tag, followed by the value read with [`read`] if the tag is `1`</br>
+    /// Any other tag than `0` or `1` results in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_option<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, ByteError>,
+    ) -> Result<Option<T>, ByteError> {
+        match read_byte(self) {
+            0 => Ok(None),
+            1 => read(self).map(Some),
+            tag => Err(ByteError::UnknownTag { tag: tag as u64 }),
+        }
+    }
+
+    /// Reads a [`Result`] as a one byte tag, `0` being followed by the value read with [`read_ok`] and `1` by the value read with [`read_err`]</br>
+    /// Any other tag results in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_result<T, E>(
+        &mut self,
+        read_ok: impl FnOnce(&mut Self) -> Result<T, ByteError>,
+        read_err: impl FnOnce(&mut Self) -> Result<E, ByteError>,
+    ) -> Result<Result<T, E>, ByteError> {
+        match read_byte(self) {
+            0 => read_ok(self).map(Ok),
+            1 => read_err(self).map(Err),
+            tag => Err(ByteError::UnknownTag { tag: tag as u64 }),
+        }
+    }
+
+    /// Reads an unsigned normalised 8-bit integer (UNORM8) as a value from `0.0` to `1.0`, advancing the readers forward by 1 byte</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unorm8(&mut self) -> f32 {
+        read_byte(self) as f32 / u8::MAX as f32
+    }
+
+    /// Reads a signed normalised 8-bit integer (SNORM8) as a value from `-1.0` to `1.0`, advancing the readers forward by 1 byte</br>
+    /// Both `-128` and `-127` are read as `-1.0`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_snorm8(&mut self) -> f32 {
+        (read_byte(self) as i8 as f32 / i8::MAX as f32).max(-1.0)
+    }
+
+    /// Reads an unsigned LEB128 variable-length integer, 7 bits at a time with the top bit set while more follow,
+    /// advancing the readers forward by 1 to 10 bytes</br>
+    /// More than 64 bits of value results in an error, though the bytes read so far are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_varint_u64(&mut self) -> Result<u64, ByteError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = read_byte(self);
+            let bits = (byte & 0x7F) as u64;
+            // The tenth byte only has room for the top bit
+            if shift == 63 && bits > 1 {
+                return Err(ByteError::OutOfRange);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ByteError::OutOfRange)
+    }
+
+    /// Reads a signed LEB128 variable-length integer stored with zigzag encoding, so small negative values stay small,
+    /// advancing the readers forward by 1 to 10 bytes</br>
+    /// More than 64 bits of value results in an error, though the bytes read so far are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_varint_i64(&mut self) -> Result<i64, ByteError> {
+        let value = self.read_varint_u64()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    /// Reads a [`View`], such as the types of [`crate::wire`], by copying its bytes, advancing the readers forward by its size</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_wire<T: View + Copy>(&mut self) -> T {
+        let byte_array = self.byte_array();
+        let size = size_of::<T>();
+        if byte_array.len() < size {
+            panic!("Attempted to read a wire value of an array without enough space within the array.");
+        }
+        let new_length = byte_array.len() - size;
+        // SAFETY: T accepts any bytes, and the length was checked above
+        let value = unsafe { byte_array.as_ptr().cast::<T>().read_unaligned() };
+        // SAFETY: The length was checked against the array above
+        unsafe {
+            self.advance(size, new_length);
+        }
+        value
+    }
+}
+
+/// Reads a single byte, for the values which have no endianness
+#[inline(always)]
+fn read_byte<R: ByteReader + ?Sized>(reader: &mut R) -> u8 {
+    let byte_array = reader.byte_array();
+    if let Some((&tag, rest)) = byte_array.split_first() {
+        let new_length = rest.len();
+        // SAFETY: There was at least one byte in the array
+        unsafe {
+            reader.advance(1, new_length);
+        }
+        tag
+    } else {
+        panic!("Attempted to read a byte of an array without enough space within the array.");
+    }
+}
+
+/// Reads `nbytes` bytes as an unsigned integer, in big-endian order if `big_endian` is set</br>
+/// Fails without advancing if `nbytes` is more than `maximum`
+#[inline(always)]
+fn read_uint_bytes<R: ByteReader + ?Sized>(reader: &mut R, nbytes: usize, maximum: usize, big_endian: bool) -> Result<u128, ByteError> {
+    if nbytes > maximum {
+        return Err(ByteError::OutOfRange);
+    }
+    let byte_array = reader.byte_array();
+    if byte_array.len() < nbytes {
+        panic!("Attempted to read an integer of an array without enough space within the array.");
+    }
+    let mut bytes = [0u8; 16];
+    let value = if big_endian {
+        bytes[16 - nbytes..].copy_from_slice(&byte_array[..nbytes]);
+        u128::from_be_bytes(bytes)
+    } else {
+        bytes[..nbytes].copy_from_slice(&byte_array[..nbytes]);
+        u128::from_le_bytes(bytes)
+    };
+    let new_length = byte_array.len() - nbytes;
+    // SAFETY: The length was checked against the array above
+    unsafe {
+        reader.advance(nbytes, new_length);
+    }
+    Ok(value)
+}
+
+/// Sign-extends the lowest `nbytes` bytes of `value`
+#[inline(always)]
+fn sign_extend(value: u128, nbytes: usize) -> i128 {
+    if nbytes == 0 {
+        return 0;
+    }
+    let shift = 128 - 8 * nbytes as u32;
+    (value << shift) as i128 >> shift
 }
 
 pub trait NativeEndianByteReader: ByteReader {
@@ -387,6 +627,58 @@ pub trait NativeEndianByteReader: ByteReader {
         }
     }
 
+    #[cfg(feature = "half")]
+    /// Reads a native-endian [`bf16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_ne(&mut self) -> bf16 {
+        let byte_size_needed = size_of::<bf16>();
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = crate::read_bf16_ne(byte_array);
+                self.advance(byte_size_needed, new_length as usize);
+                data
+            }
+        } else {
+            panic!("Attempted to read bf16 of native-endian of an array without enough space within the array.");
+        }
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a native-endian [`f16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_as_f32_ne(&mut self) -> f32 {
+        self.read_f16_ne().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a native-endian [`bf16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_as_f32_ne(&mut self) -> f32 {
+        self.read_bf16_ne().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] native-endian [`f16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_slice_as_f32_ne(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_f16_as_f32_ne()).collect()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] native-endian [`bf16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_slice_as_f32_ne(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_bf16_as_f32_ne()).collect()
+    }
+
     /// Reads a native-endian [`f32`] from the byte array, advancing the readers forward by 4 bytes</br>
     /// Panics if there is not enough space
     #[inline(always)]
@@ -442,6 +734,185 @@ pub trait NativeEndianByteReader: ByteReader {
             panic!("Attempted to read custom number bytes of an array without space in the array.");
         }
     }
+
+    /// Reads a native-endian [`char`] stored as its 4 byte scalar value, advancing the readers forward by 4 bytes</br>
+    /// Surrogates and values above [`char::MAX`] result in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_char_ne(&mut self) -> Result<char, ByteError> {
+        let value = self.read_u32_ne();
+        char::from_u32(value).ok_or(ByteError::InvalidChar { value })
+    }
+
+    /// Reads a native-endian [`NonZeroU8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u8_ne(&mut self) -> Result<NonZeroU8, ByteError> {
+        NonZeroU8::new(self.read_u8_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroU16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u16_ne(&mut self) -> Result<NonZeroU16, ByteError> {
+        NonZeroU16::new(self.read_u16_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroU32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u32_ne(&mut self) -> Result<NonZeroU32, ByteError> {
+        NonZeroU32::new(self.read_u32_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroU64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u64_ne(&mut self) -> Result<NonZeroU64, ByteError> {
+        NonZeroU64::new(self.read_u64_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroU128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u128_ne(&mut self) -> Result<NonZeroU128, ByteError> {
+        NonZeroU128::new(self.read_u128_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroUsize`] from the byte array, advancing the readers forward by [`size_of::<usize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_usize_ne(&mut self) -> Result<NonZeroUsize, ByteError> {
+        NonZeroUsize::new(self.read_usize_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroI8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i8_ne(&mut self) -> Result<NonZeroI8, ByteError> {
+        NonZeroI8::new(self.read_i8_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroI16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i16_ne(&mut self) -> Result<NonZeroI16, ByteError> {
+        NonZeroI16::new(self.read_i16_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroI32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i32_ne(&mut self) -> Result<NonZeroI32, ByteError> {
+        NonZeroI32::new(self.read_i32_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroI64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i64_ne(&mut self) -> Result<NonZeroI64, ByteError> {
+        NonZeroI64::new(self.read_i64_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroI128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i128_ne(&mut self) -> Result<NonZeroI128, ByteError> {
+        NonZeroI128::new(self.read_i128_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian [`NonZeroIsize`] from the byte array, advancing the readers forward by [`size_of::<isize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_isize_ne(&mut self) -> Result<NonZeroIsize, ByteError> {
+        NonZeroIsize::new(self.read_isize_ne()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a native-endian signed Q16.16 fixed-point number, advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_q16_16_ne(&mut self) -> f64 {
+        self.read_i32_ne() as f64 / 65536.0
+    }
+
+    /// Reads a native-endian signed 2.14 fixed-point number (F2DOT14), advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f2dot14_ne(&mut self) -> f32 {
+        self.read_i16_ne() as f32 / 16384.0
+    }
+
+    /// Reads a native-endian unsigned normalised 16-bit integer (UNORM16) as a value from `0.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unorm16_ne(&mut self) -> f32 {
+        self.read_u16_ne() as f32 / u16::MAX as f32
+    }
+
+    /// Reads a native-endian signed normalised 16-bit integer (SNORM16) as a value from `-1.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Both `-32768` and `-32767` are read as `-1.0`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_snorm16_ne(&mut self) -> f32 {
+        (self.read_i16_ne() as f32 / i16::MAX as f32).max(-1.0)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] native-endian bytes as a `u64`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint_ne(&mut self, nbytes: usize) -> Result<u64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, cfg!(target_endian = "big"))?;
+        Ok(value as u64)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] native-endian bytes as a `i64`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int_ne(&mut self, nbytes: usize) -> Result<i64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, cfg!(target_endian = "big"))?;
+        Ok(sign_extend(value, nbytes) as i64)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] native-endian bytes as a `u128`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint128_ne(&mut self, nbytes: usize) -> Result<u128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, cfg!(target_endian = "big"))?;
+        Ok(value)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] native-endian bytes as a `i128`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int128_ne(&mut self, nbytes: usize) -> Result<i128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, cfg!(target_endian = "big"))?;
+        Ok(sign_extend(value, nbytes))
+    }
+
+    /// Decodes a [`HyperDecode`] value in native-endian, with `u32` length prefixes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn decode_ne<T: HyperDecode<NativeEndian>>(&mut self) -> Result<T, ByteError> {
+        EndianReader::<NativeEndian>::decode(self)
+    }
 }
 
 pub trait LittleEndianByteReader: ByteReader {
@@ -693,6 +1164,58 @@ pub trait LittleEndianByteReader: ByteReader {
         }
     }
 
+    #[cfg(feature = "half")]
+    /// Reads a little-endian [`bf16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_le(&mut self) -> bf16 {
+        let byte_size_needed = size_of::<bf16>();
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = crate::read_bf16_le(byte_array);
+                self.advance(byte_size_needed, new_length as usize);
+                data
+            }
+        } else {
+            panic!("Attempted to read bf16 of little-endian of an array without enough space within the array.");
+        }
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a little-endian [`f16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_as_f32_le(&mut self) -> f32 {
+        self.read_f16_le().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a little-endian [`bf16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_as_f32_le(&mut self) -> f32 {
+        self.read_bf16_le().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] little-endian [`f16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_slice_as_f32_le(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_f16_as_f32_le()).collect()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] little-endian [`bf16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_slice_as_f32_le(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_bf16_as_f32_le()).collect()
+    }
+
     /// Reads a little-endian [`f32`] from the byte array, advancing the readers forward by 4 bytes</br>
     /// Panics if there is not enough space
     #[inline(always)]
@@ -748,40 +1271,325 @@ pub trait LittleEndianByteReader: ByteReader {
             panic!("Attempted to read custom number bytes of an array without space in the array.");
         }
     }
-}
 
-pub trait BigEndianByteReader: ByteReader {
-    /// Reads a big-endian [`u8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// Reads [`len_units`] little-endian UTF-16 code units into a [`String`], advancing the readers forward by `2 * len_units` bytes</br>
+    /// Unpaired surrogates result in an error, though the code units are still consumed</br>
     /// Panics if there is not enough space
     #[inline(always)]
-    fn read_u8_be(&mut self) -> u8 {
-        let byte_size_needed = size_of::<u8>();
-        let byte_array = self.byte_array();
-        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
-        if new_length >= 0 {
-            // SAFETY: This will only execute if it is within bounds of the array
-            unsafe {
-                let data = crate::read_u8_be(byte_array);
-                self.advance(byte_size_needed, new_length as usize);
-                data
-            }
-        } else {
-            panic!("Attempted to read u8 of big-endian of an array without enough space within the array.");
-        }
+    fn read_utf16_le(&mut self, len_units: usize) -> Result<String, ByteError> {
+        let units: Vec<u16> = (0..len_units).map(|_| self.read_u16_le()).collect();
+        decode_utf16(units.iter().copied())
+            .map(|result| result.map_err(|error| ByteError::InvalidUtf16 { unit: error.unpaired_surrogate() }))
+            .collect()
     }
 
-    /// Reads a big-endian [`u16`] from the byte array, advancing the readers forward by 2 byte</br>
+    /// Reads [`len_units`] little-endian UTF-16 code units into a [`String`], advancing the readers forward by `2 * len_units` bytes</br>
+    /// Unpaired surrogates are replaced with [`char::REPLACEMENT_CHARACTER`]</br>
     /// Panics if there is not enough space
     #[inline(always)]
-    fn read_u16_be(&mut self) -> u16 {
-        let byte_size_needed = size_of::<u16>();
-        let byte_array = self.byte_array();
-        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
-        if new_length >= 0 {
-            // SAFETY: This will only execute if it is within bounds of the array
-            unsafe {
-                let data = crate::read_u16_be(byte_array);
-                self.advance(byte_size_needed, new_length as usize);
+    fn read_utf16_le_lossy(&mut self, len_units: usize) -> String {
+        let units: Vec<u16> = (0..len_units).map(|_| self.read_u16_le()).collect();
+        decode_utf16(units.iter().copied())
+            .map(|result| result.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    /// Reads a little-endian UTF-16 string prefixed by its length in code units as a little-endian [`u16`]</br>
+    /// Unpaired surrogates result in an error, though the code units are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_utf16_le_prefixed(&mut self) -> Result<String, ByteError> {
+        let len_units = self.read_u16_le() as usize;
+        self.read_utf16_le(len_units)
+    }
+
+    /// Reads a little-endian [`char`] stored as its 4 byte scalar value, advancing the readers forward by 4 bytes</br>
+    /// Surrogates and values above [`char::MAX`] result in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_char_le(&mut self) -> Result<char, ByteError> {
+        let value = self.read_u32_le();
+        char::from_u32(value).ok_or(ByteError::InvalidChar { value })
+    }
+
+    /// Reads a little-endian [`NonZeroU8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u8_le(&mut self) -> Result<NonZeroU8, ByteError> {
+        NonZeroU8::new(self.read_u8_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroU16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u16_le(&mut self) -> Result<NonZeroU16, ByteError> {
+        NonZeroU16::new(self.read_u16_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroU32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u32_le(&mut self) -> Result<NonZeroU32, ByteError> {
+        NonZeroU32::new(self.read_u32_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroU64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u64_le(&mut self) -> Result<NonZeroU64, ByteError> {
+        NonZeroU64::new(self.read_u64_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroU128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u128_le(&mut self) -> Result<NonZeroU128, ByteError> {
+        NonZeroU128::new(self.read_u128_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroUsize`] from the byte array, advancing the readers forward by [`size_of::<usize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_usize_le(&mut self) -> Result<NonZeroUsize, ByteError> {
+        NonZeroUsize::new(self.read_usize_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroI8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i8_le(&mut self) -> Result<NonZeroI8, ByteError> {
+        NonZeroI8::new(self.read_i8_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroI16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i16_le(&mut self) -> Result<NonZeroI16, ByteError> {
+        NonZeroI16::new(self.read_i16_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroI32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i32_le(&mut self) -> Result<NonZeroI32, ByteError> {
+        NonZeroI32::new(self.read_i32_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroI64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i64_le(&mut self) -> Result<NonZeroI64, ByteError> {
+        NonZeroI64::new(self.read_i64_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroI128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i128_le(&mut self) -> Result<NonZeroI128, ByteError> {
+        NonZeroI128::new(self.read_i128_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a little-endian [`NonZeroIsize`] from the byte array, advancing the readers forward by [`size_of::<isize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_isize_le(&mut self) -> Result<NonZeroIsize, ByteError> {
+        NonZeroIsize::new(self.read_isize_le()).ok_or(ByteError::ZeroValue)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a little-endian [`u32`] of seconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_u32_le(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_secs(self.read_u32_le() as u64))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a little-endian [`u64`] of seconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_u64_le(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_secs(self.read_u64_le()))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a little-endian [`u64`] of seconds since the Unix epoch followed by a little-endian [`u32`] of nanoseconds as a [`SystemTime`], advancing the readers forward by 12 bytes</br>
+    /// Nanoseconds of a second or more, and times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_nanos_le(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(self.read_duration_le()?)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a little-endian [`u64`] of milliseconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_millis_le(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_millis(self.read_u64_le()))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a little-endian [`u64`] of seconds followed by a little-endian [`u32`] of nanoseconds as a [`Duration`], advancing the readers forward by 12 bytes</br>
+    /// Nanoseconds of a second or more result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_duration_le(&mut self) -> Result<Duration, ByteError> {
+        let seconds = self.read_u64_le();
+        time::duration(seconds, self.read_u32_le())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a Windows FILETIME (100 nanosecond ticks since 1601) as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_filetime_le(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_filetime(self.read_u64_le())
+    }
+
+    /// Reads a Microsoft GUID, where the first three fields are little-endian, advancing the readers forward by 16 bytes</br>
+    /// The returned bytes are in the RFC 4122 big-endian layout, the same as [`BigEndianByteReader::read_uuid_be`]</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_guid_le(&mut self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&self.read_u32_le().to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.read_u16_le().to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.read_u16_le().to_be_bytes());
+        bytes[8..16].copy_from_slice(&self.read_u64_le().to_le_bytes());
+        bytes
+    }
+
+    #[cfg(feature = "uuid")]
+    /// Reads a Microsoft GUID as a [`Uuid`], where the first three fields are little-endian, advancing the readers forward by 16 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_guid_typed_le(&mut self) -> Uuid {
+        Uuid::from_bytes(self.read_guid_le())
+    }
+
+    /// Reads a little-endian signed Q16.16 fixed-point number, advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_q16_16_le(&mut self) -> f64 {
+        self.read_i32_le() as f64 / 65536.0
+    }
+
+    /// Reads a little-endian signed 2.14 fixed-point number (F2DOT14), advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f2dot14_le(&mut self) -> f32 {
+        self.read_i16_le() as f32 / 16384.0
+    }
+
+    /// Reads a little-endian unsigned normalised 16-bit integer (UNORM16) as a value from `0.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unorm16_le(&mut self) -> f32 {
+        self.read_u16_le() as f32 / u16::MAX as f32
+    }
+
+    /// Reads a little-endian signed normalised 16-bit integer (SNORM16) as a value from `-1.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Both `-32768` and `-32767` are read as `-1.0`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_snorm16_le(&mut self) -> f32 {
+        (self.read_i16_le() as f32 / i16::MAX as f32).max(-1.0)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] little-endian bytes as a `u64`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint_le(&mut self, nbytes: usize) -> Result<u64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, false)?;
+        Ok(value as u64)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] little-endian bytes as a `i64`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int_le(&mut self, nbytes: usize) -> Result<i64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, false)?;
+        Ok(sign_extend(value, nbytes) as i64)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] little-endian bytes as a `u128`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint128_le(&mut self, nbytes: usize) -> Result<u128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, false)?;
+        Ok(value)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] little-endian bytes as a `i128`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int128_le(&mut self, nbytes: usize) -> Result<i128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, false)?;
+        Ok(sign_extend(value, nbytes))
+    }
+
+    /// Decodes a [`HyperDecode`] value in little-endian, with `u32` length prefixes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn decode_le<T: HyperDecode<LittleEndian>>(&mut self) -> Result<T, ByteError> {
+        EndianReader::<LittleEndian>::decode(self)
+    }
+}
+
+pub trait BigEndianByteReader: ByteReader {
+    /// Reads a big-endian [`u8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_u8_be(&mut self) -> u8 {
+        let byte_size_needed = size_of::<u8>();
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = crate::read_u8_be(byte_array);
+                self.advance(byte_size_needed, new_length as usize);
+                data
+            }
+        } else {
+            panic!("Attempted to read u8 of big-endian of an array without enough space within the array.");
+        }
+    }
+
+    /// Reads a big-endian [`u16`] from the byte array, advancing the readers forward by 2 byte</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_u16_be(&mut self) -> u16 {
+        let byte_size_needed = size_of::<u16>();
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = crate::read_u16_be(byte_array);
+                self.advance(byte_size_needed, new_length as usize);
                 data
             }
         } else {
@@ -999,6 +1807,58 @@ pub trait BigEndianByteReader: ByteReader {
         }
     }
 
+    #[cfg(feature = "half")]
+    /// Reads a big-endian [`bf16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_be(&mut self) -> bf16 {
+        let byte_size_needed = size_of::<bf16>();
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = crate::read_bf16_be(byte_array);
+                self.advance(byte_size_needed, new_length as usize);
+                data
+            }
+        } else {
+            panic!("Attempted to read bf16 of big-endian of an array without enough space within the array.");
+        }
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a big-endian [`f16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_as_f32_be(&mut self) -> f32 {
+        self.read_f16_be().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads a big-endian [`bf16`] from the byte array and converts it into an [`f32`], advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_as_f32_be(&mut self) -> f32 {
+        self.read_bf16_be().to_f32()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] big-endian [`f16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f16_slice_as_f32_be(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_f16_as_f32_be()).collect()
+    }
+
+    #[cfg(feature = "half")]
+    /// Reads [`count`] big-endian [`bf16`]s from the byte array and converts them into [`f32`]s, advancing the readers forward by `2 * count` bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_bf16_slice_as_f32_be(&mut self, count: usize) -> Vec<f32> {
+        (0..count).map(|_| self.read_bf16_as_f32_be()).collect()
+    }
+
     /// Reads a big-endian [`f32`] from the byte array, advancing the readers forward by 4 bytes</br>
     /// Panics if there is not enough space
     #[inline(always)]
@@ -1054,4 +1914,338 @@ pub trait BigEndianByteReader: ByteReader {
             panic!("Attempted to read custom number bytes of an array without space in the array.");
         }
     }
+
+    /// Reads [`len_units`] big-endian UTF-16 code units into a [`String`], advancing the readers forward by `2 * len_units` bytes</br>
+    /// Unpaired surrogates result in an error, though the code units are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_utf16_be(&mut self, len_units: usize) -> Result<String, ByteError> {
+        let units: Vec<u16> = (0..len_units).map(|_| self.read_u16_be()).collect();
+        decode_utf16(units.iter().copied())
+            .map(|result| result.map_err(|error| ByteError::InvalidUtf16 { unit: error.unpaired_surrogate() }))
+            .collect()
+    }
+
+    /// Reads [`len_units`] big-endian UTF-16 code units into a [`String`], advancing the readers forward by `2 * len_units` bytes</br>
+    /// Unpaired surrogates are replaced with [`char::REPLACEMENT_CHARACTER`]</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_utf16_be_lossy(&mut self, len_units: usize) -> String {
+        let units: Vec<u16> = (0..len_units).map(|_| self.read_u16_be()).collect();
+        decode_utf16(units.iter().copied())
+            .map(|result| result.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    /// Reads a big-endian UTF-16 string prefixed by its length in code units as a big-endian [`u16`]</br>
+    /// Unpaired surrogates result in an error, though the code units are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_utf16_be_prefixed(&mut self) -> Result<String, ByteError> {
+        let len_units = self.read_u16_be() as usize;
+        self.read_utf16_be(len_units)
+    }
+
+    /// Reads a big-endian [`char`] stored as its 4 byte scalar value, advancing the readers forward by 4 bytes</br>
+    /// Surrogates and values above [`char::MAX`] result in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_char_be(&mut self) -> Result<char, ByteError> {
+        let value = self.read_u32_be();
+        char::from_u32(value).ok_or(ByteError::InvalidChar { value })
+    }
+
+    /// Reads a big-endian [`NonZeroU8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u8_be(&mut self) -> Result<NonZeroU8, ByteError> {
+        NonZeroU8::new(self.read_u8_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroU16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u16_be(&mut self) -> Result<NonZeroU16, ByteError> {
+        NonZeroU16::new(self.read_u16_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroU32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u32_be(&mut self) -> Result<NonZeroU32, ByteError> {
+        NonZeroU32::new(self.read_u32_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroU64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u64_be(&mut self) -> Result<NonZeroU64, ByteError> {
+        NonZeroU64::new(self.read_u64_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroU128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_u128_be(&mut self) -> Result<NonZeroU128, ByteError> {
+        NonZeroU128::new(self.read_u128_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroUsize`] from the byte array, advancing the readers forward by [`size_of::<usize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_usize_be(&mut self) -> Result<NonZeroUsize, ByteError> {
+        NonZeroUsize::new(self.read_usize_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroI8`] from the byte array, advancing the readers forward by 1 byte</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i8_be(&mut self) -> Result<NonZeroI8, ByteError> {
+        NonZeroI8::new(self.read_i8_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroI16`] from the byte array, advancing the readers forward by 2 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i16_be(&mut self) -> Result<NonZeroI16, ByteError> {
+        NonZeroI16::new(self.read_i16_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroI32`] from the byte array, advancing the readers forward by 4 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i32_be(&mut self) -> Result<NonZeroI32, ByteError> {
+        NonZeroI32::new(self.read_i32_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroI64`] from the byte array, advancing the readers forward by 8 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i64_be(&mut self) -> Result<NonZeroI64, ByteError> {
+        NonZeroI64::new(self.read_i64_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroI128`] from the byte array, advancing the readers forward by 16 bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_i128_be(&mut self) -> Result<NonZeroI128, ByteError> {
+        NonZeroI128::new(self.read_i128_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    /// Reads a big-endian [`NonZeroIsize`] from the byte array, advancing the readers forward by [`size_of::<isize>()`] bytes</br>
+    /// A zero results in an error, though the bytes are still consumed</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_nonzero_isize_be(&mut self) -> Result<NonZeroIsize, ByteError> {
+        NonZeroIsize::new(self.read_isize_be()).ok_or(ByteError::ZeroValue)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads an [`Ipv4Addr`] in network order, advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_ipv4(&mut self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(self.read_u32_be())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads an [`Ipv6Addr`] in network order, advancing the readers forward by 16 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_ipv6(&mut self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(self.read_u128_be())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a [`SocketAddrV4`] as its address followed by a big-endian port, advancing the readers forward by 6 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_socket_addr_v4(&mut self) -> SocketAddrV4 {
+        let ip = self.read_ipv4();
+        SocketAddrV4::new(ip, self.read_u16_be())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a [`SocketAddrV6`] as its address followed by a big-endian port, advancing the readers forward by 18 bytes</br>
+    /// The flow info and scope id are not part of the encoding and are left as `0`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_socket_addr_v6(&mut self) -> SocketAddrV6 {
+        let ip = self.read_ipv6();
+        SocketAddrV6::new(ip, self.read_u16_be(), 0, 0)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a 6 byte MAC address, advancing the readers forward by 6 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_mac(&mut self) -> [u8; 6] {
+        let byte_size_needed = 6;
+        let byte_array = self.byte_array();
+        let new_length = (byte_array.len() as isize) - byte_size_needed as isize;
+        if new_length >= 0 {
+            // SAFETY: This will only execute if it is within bounds of the array
+            unsafe {
+                let data = *(byte_array.as_ptr() as *const [u8; 6]);
+                self.advance(byte_size_needed, new_length as usize);
+                data
+            }
+        } else {
+            panic!("Attempted to read a MAC address of an array without enough space within the array.");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a big-endian [`u32`] of seconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_u32_be(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_secs(self.read_u32_be() as u64))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a big-endian [`u64`] of seconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_u64_be(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_secs(self.read_u64_be()))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a big-endian [`u64`] of seconds since the Unix epoch followed by a big-endian [`u32`] of nanoseconds as a [`SystemTime`], advancing the readers forward by 12 bytes</br>
+    /// Nanoseconds of a second or more, and times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_secs_nanos_be(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(self.read_duration_be()?)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a big-endian [`u64`] of milliseconds since the Unix epoch as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unix_millis_be(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_unix(Duration::from_millis(self.read_u64_be()))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a big-endian [`u64`] of seconds followed by a big-endian [`u32`] of nanoseconds as a [`Duration`], advancing the readers forward by 12 bytes</br>
+    /// Nanoseconds of a second or more result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_duration_be(&mut self) -> Result<Duration, ByteError> {
+        let seconds = self.read_u64_be();
+        time::duration(seconds, self.read_u32_be())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads a 64-bit NTP timestamp (32.32 fixed-point seconds since 1900) as a [`SystemTime`], advancing the readers forward by 8 bytes</br>
+    /// Times the platform cannot represent result in an error</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_ntp_timestamp_be(&mut self) -> Result<SystemTime, ByteError> {
+        time::from_ntp(self.read_u64_be())
+    }
+
+    /// Reads a UUID in its RFC 4122 big-endian layout, advancing the readers forward by 16 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uuid_be(&mut self) -> [u8; 16] {
+        self.read_u128_be().to_be_bytes()
+    }
+
+    #[cfg(feature = "uuid")]
+    /// Reads a [`Uuid`] in its RFC 4122 big-endian layout, advancing the readers forward by 16 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uuid_typed_be(&mut self) -> Uuid {
+        Uuid::from_bytes(self.read_uuid_be())
+    }
+
+    /// Reads a big-endian signed Q16.16 fixed-point number, advancing the readers forward by 4 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_q16_16_be(&mut self) -> f64 {
+        self.read_i32_be() as f64 / 65536.0
+    }
+
+    /// Reads a big-endian signed 2.14 fixed-point number (F2DOT14), advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_f2dot14_be(&mut self) -> f32 {
+        self.read_i16_be() as f32 / 16384.0
+    }
+
+    /// Reads a big-endian unsigned normalised 16-bit integer (UNORM16) as a value from `0.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_unorm16_be(&mut self) -> f32 {
+        self.read_u16_be() as f32 / u16::MAX as f32
+    }
+
+    /// Reads a big-endian signed normalised 16-bit integer (SNORM16) as a value from `-1.0` to `1.0`, advancing the readers forward by 2 bytes</br>
+    /// Both `-32768` and `-32767` are read as `-1.0`</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_snorm16_be(&mut self) -> f32 {
+        (self.read_i16_be() as f32 / i16::MAX as f32).max(-1.0)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] big-endian bytes as a `u64`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint_be(&mut self, nbytes: usize) -> Result<u64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, true)?;
+        Ok(value as u64)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] big-endian bytes as a `i64`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 8 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int_be(&mut self, nbytes: usize) -> Result<i64, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 8, true)?;
+        Ok(sign_extend(value, nbytes) as i64)
+    }
+
+    /// Reads an unsigned integer stored in [`nbytes`] big-endian bytes as a `u128`, advancing the readers forward by `nbytes` bytes</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_uint128_be(&mut self, nbytes: usize) -> Result<u128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, true)?;
+        Ok(value)
+    }
+
+    /// Reads a signed integer stored in [`nbytes`] big-endian bytes as a `i128`, advancing the readers forward by `nbytes` bytes</br>
+    /// The value is sign-extended from its highest bit</br>
+    /// A width of more than 16 bytes results in an error without advancing</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn read_int128_be(&mut self, nbytes: usize) -> Result<i128, ByteError> {
+        let value = read_uint_bytes(self, nbytes, 16, true)?;
+        Ok(sign_extend(value, nbytes))
+    }
+
+    /// Decodes a [`HyperDecode`] value in big-endian, with `u32` length prefixes</br>
+    /// Panics if there is not enough space
+    #[inline(always)]
+    fn decode_be<T: HyperDecode<BigEndian>>(&mut self) -> Result<T, ByteError> {
+        EndianReader::<BigEndian>::decode(self)
+    }
 }
@@ -0,0 +1,50 @@
+//! Byte searching used by the delimiter based readers.<br/>
+//! It checks a whole `usize` worth of bytes per step instead of going one `u8` at a time.
+
+const LANE: usize = size_of::<usize>();
+const LOW_BITS: usize = usize::from_ne_bytes([0x01; LANE]);
+const HIGH_BITS: usize = usize::from_ne_bytes([0x80; LANE]);
+
+/// Whether any byte inside of `word` is zero, see "Bit Twiddling Hacks" for the trick itself
+#[inline(always)]
+const fn contains_zero_byte(word: usize) -> bool {
+    word.wrapping_sub(LOW_BITS) & !word & HIGH_BITS != 0
+}
+
+/// Returns the index of the first `needle` inside of `haystack`
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let repeated = LOW_BITS * needle as usize;
+    let mut offset = 0;
+    let mut chunks = haystack.chunks_exact(LANE);
+    for chunk in &mut chunks {
+        // SAFETY: chunks_exact guarantees each chunk is exactly LANE bytes long
+        let word = unsafe { usize::from_ne_bytes(*(chunk.as_ptr() as *const [u8; LANE])) };
+        if contains_zero_byte(word ^ repeated) {
+            break;
+        }
+        offset += LANE;
+    }
+    haystack[offset..]
+        .iter()
+        .position(|&byte| byte == needle)
+        .map(|index| index + offset)
+}
+
+/// Returns the index of the first occurrence of `pattern` inside of `haystack`.<br/>
+/// An empty pattern is always found at the start.
+#[inline]
+pub(crate) fn find_slice(haystack: &[u8], pattern: &[u8]) -> Option<usize> {
+    let Some((&first, rest)) = pattern.split_first() else {
+        return Some(0);
+    };
+    let mut offset = 0;
+    while haystack.len() - offset >= pattern.len() {
+        let candidate = offset + find_byte(&haystack[offset..=haystack.len() - pattern.len()], first)?;
+        if &haystack[candidate + 1..candidate + pattern.len()] == rest {
+            return Some(candidate);
+        }
+        offset = candidate + 1;
+    }
+    None
+}
@@ -0,0 +1,1111 @@
+//! A compact binary format for serde, writing each value in order with the endian writer traits and
+//! reading it back with the endian reader traits.<br/>
+//! Only the lengths of strings, byte arrays, sequences and maps, the presence of an `Option` and the
+//! index of an enum variant are stored besides the values themselves, so both ends must agree on the
+//! types, as with [`HyperRead`](crate::derive::HyperRead) and [`HyperWrite`](crate::derive::HyperWrite).
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::serde::{from_slice_be, to_vec_be};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Player<'a> {
+//!     id: u32,
+//!     name: &'a str,
+//!     position: (f32, f32),
+//! }
+//!
+//! let player = Player { id: 7, name: "Ann", position: (1.0, -1.0) };
+//! let buffer = to_vec_be(&player).unwrap();
+//! assert_eq!(buffer, [0, 0, 0, 7, 0, 0, 0, 3, b'A', b'n', b'n', 0x3F, 0x80, 0, 0, 0xBF, 0x80, 0, 0]);
+//!
+//! // The name borrows from the buffer instead of being copied
+//! let read: Player = from_slice_be(&buffer).unwrap();
+//! assert_eq!(read, player);
+//! ```
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use ::serde::ser::{self, Serialize};
+use ::serde::Deserialize;
+use crate::endian::{BigEndian, EndianReader, EndianWriter, LittleEndian};
+use crate::error::ByteError;
+use crate::reader::FastByteReader;
+use crate::readers::traits::{BigEndianByteReader, ByteReader, LittleEndianByteReader};
+use crate::writer::FastByteWriter;
+use crate::writers::traits::{BigEndianByteWriter, ByteWriter, LittleEndianByteWriter};
+
+/// How the integers wider than a byte are stored
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum IntEncoding {
+    /// Every integer takes its full width, in the endian of the call
+    #[default]
+    Fixed,
+    /// Unsigned integers are stored as LEB128, and signed integers zigzag encoded first.<br/>
+    /// `u128` and `i128` always take their full width
+    Varint,
+}
+
+/// How the lengths of strings, byte arrays, sequences and maps are stored
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LengthEncoding {
+    U8,
+    U16,
+    #[default]
+    U32,
+    U64,
+    /// An unsigned LEB128 variable-length integer
+    Varint,
+}
+
+impl LengthEncoding {
+    /// The longest length the prefix can hold
+    const fn maximum(self) -> usize {
+        match self {
+            LengthEncoding::U8 => u8::MAX as usize,
+            LengthEncoding::U16 => u16::MAX as usize,
+            LengthEncoding::U32 if usize::BITS > 32 => u32::MAX as usize,
+            _ => usize::MAX,
+        }
+    }
+}
+
+/// The options of the format, which must be the same when writing and reading.<br/>
+/// The default stores integers at their full width, with `u32` length prefixes
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Config {
+    pub integers: IntEncoding,
+    pub lengths: LengthEncoding,
+}
+
+impl Config {
+    /// Fixed width integers with `u32` length prefixes
+    pub const FIXED: Config = Config { integers: IntEncoding::Fixed, lengths: LengthEncoding::U32 };
+    /// Variable-length integers and length prefixes, for the smallest output
+    pub const VARINT: Config = Config { integers: IntEncoding::Varint, lengths: LengthEncoding::Varint };
+}
+
+impl ser::Error for ByteError {
+    fn custom<T: Display>(message: T) -> Self {
+        ByteError::Custom { message: message.to_string() }
+    }
+}
+
+impl de::Error for ByteError {
+    fn custom<T: Display>(message: T) -> Self {
+        ByteError::Custom { message: message.to_string() }
+    }
+}
+
+/// Serializes the value with its integers in big-endian and the default [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::to_vec_be;
+///
+/// assert_eq!(to_vec_be(&(1u16, "hi")).unwrap(), [0, 1, 0, 0, 0, 2, b'h', b'i']);
+/// ```
+pub fn to_vec_be<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, ByteError> {
+    to_vec::<BigEndian, T>(value, Config::default())
+}
+
+/// Serializes the value with its integers in little-endian and the default [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::to_vec_le;
+///
+/// assert_eq!(to_vec_le(&(1u16, "hi")).unwrap(), [1, 0, 2, 0, 0, 0, b'h', b'i']);
+/// ```
+pub fn to_vec_le<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, ByteError> {
+    to_vec::<LittleEndian, T>(value, Config::default())
+}
+
+/// Serializes the value with its integers in big-endian, encoded as given by the [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{to_vec_be_with, Config};
+///
+/// assert_eq!(to_vec_be_with(&(300u32, "hi"), Config::VARINT).unwrap(), [0xAC, 0x02, 2, b'h', b'i']);
+/// ```
+pub fn to_vec_be_with<T: Serialize + ?Sized>(value: &T, config: Config) -> Result<Vec<u8>, ByteError> {
+    to_vec::<BigEndian, T>(value, config)
+}
+
+/// Serializes the value with its integers in little-endian, encoded as given by the [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{to_vec_le_with, Config, IntEncoding, LengthEncoding};
+///
+/// let config = Config { integers: IntEncoding::Fixed, lengths: LengthEncoding::U8 };
+/// assert_eq!(to_vec_le_with(&(300u32, "hi"), config).unwrap(), [0x2C, 0x01, 0, 0, 2, b'h', b'i']);
+/// ```
+pub fn to_vec_le_with<T: Serialize + ?Sized>(value: &T, config: Config) -> Result<Vec<u8>, ByteError> {
+    to_vec::<LittleEndian, T>(value, config)
+}
+
+/// Deserializes a value written by [`to_vec_be`], borrowing any `&str` and `&[u8]` from the bytes.<br/>
+/// Any bytes after the value are left unread, and bytes ending early result in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::from_slice_be;
+///
+/// let value: (u16, &str) = from_slice_be(&[0, 1, 0, 0, 0, 2, b'h', b'i']).unwrap();
+/// assert_eq!(value, (1, "hi"));
+/// ```
+pub fn from_slice_be<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, ByteError> {
+    from_slice::<BigEndian, T>(bytes, Config::default())
+}
+
+/// Deserializes a value written by [`to_vec_le`], borrowing any `&str` and `&[u8]` from the bytes.<br/>
+/// Any bytes after the value are left unread, and bytes ending early result in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::from_slice_le;
+///
+/// let value: (u16, &str) = from_slice_le(&[1, 0, 2, 0, 0, 0, b'h', b'i']).unwrap();
+/// assert_eq!(value, (1, "hi"));
+/// ```
+pub fn from_slice_le<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, ByteError> {
+    from_slice::<LittleEndian, T>(bytes, Config::default())
+}
+
+/// Deserializes a value written by [`to_vec_be_with`] with the same [`Config`].<br/>
+/// Any bytes after the value are left unread, and bytes ending early result in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{from_slice_be_with, Config};
+///
+/// let value: (u32, &str) = from_slice_be_with(&[0xAC, 0x02, 2, b'h', b'i'], Config::VARINT).unwrap();
+/// assert_eq!(value, (300, "hi"));
+/// ```
+pub fn from_slice_be_with<'de, T: Deserialize<'de>>(bytes: &'de [u8], config: Config) -> Result<T, ByteError> {
+    from_slice::<BigEndian, T>(bytes, config)
+}
+
+/// Deserializes a value written by [`to_vec_le_with`] with the same [`Config`].<br/>
+/// Any bytes after the value are left unread, and bytes ending early result in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{from_slice_le_with, Config, IntEncoding, LengthEncoding};
+///
+/// let config = Config { integers: IntEncoding::Fixed, lengths: LengthEncoding::U8 };
+/// let value: (u32, &str) = from_slice_le_with(&[0x2C, 0x01, 0, 0, 2, b'h', b'i'], config).unwrap();
+/// assert_eq!(value, (300, "hi"));
+/// ```
+pub fn from_slice_le_with<'de, T: Deserialize<'de>>(bytes: &'de [u8], config: Config) -> Result<T, ByteError> {
+    from_slice::<LittleEndian, T>(bytes, config)
+}
+
+/// Serializes the value into the writer with its integers in big-endian and the default [`Config`], the same
+/// as [`to_vec_be`] writes
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::to_writer_be;
+/// use hyper_byte::writer::NetworkWriter;
+/// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+///
+/// let mut writer = NetworkWriter::new();
+/// writer.write_u8_be(9);
+/// to_writer_be(&mut writer, &(1u16, "hi")).unwrap();
+/// assert_eq!(writer.to_vec(), [9, 0, 1, 0, 0, 0, 2, b'h', b'i']);
+/// ```
+pub fn to_writer_be<W: BigEndianByteWriter + ?Sized, T: Serialize + ?Sized>(
+    writer: &mut W,
+    value: &T,
+) -> Result<(), ByteError> {
+    to_writer::<BigEndian, W, T>(writer, value, Config::default())
+}
+
+/// Serializes the value into the writer with its integers in little-endian and the default [`Config`], the same
+/// as [`to_vec_le`] writes
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::to_writer_le;
+/// use hyper_byte::writer::LittleWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// let mut writer = LittleWriter::new();
+/// to_writer_le(&mut writer, &(1u16, "hi")).unwrap();
+/// assert_eq!(writer.to_vec(), [1, 0, 2, 0, 0, 0, b'h', b'i']);
+/// ```
+pub fn to_writer_le<W: LittleEndianByteWriter + ?Sized, T: Serialize + ?Sized>(
+    writer: &mut W,
+    value: &T,
+) -> Result<(), ByteError> {
+    to_writer::<LittleEndian, W, T>(writer, value, Config::default())
+}
+
+/// Serializes the value into the writer with its integers in big-endian, encoded as given by the [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{to_writer_be_with, Config};
+/// use hyper_byte::writer::NetworkWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// let mut writer = NetworkWriter::new();
+/// to_writer_be_with(&mut writer, &(300u32, "hi"), Config::VARINT).unwrap();
+/// assert_eq!(writer.to_vec(), [0xAC, 0x02, 2, b'h', b'i']);
+/// ```
+pub fn to_writer_be_with<W: BigEndianByteWriter + ?Sized, T: Serialize + ?Sized>(
+    writer: &mut W,
+    value: &T,
+    config: Config,
+) -> Result<(), ByteError> {
+    to_writer::<BigEndian, W, T>(writer, value, config)
+}
+
+/// Serializes the value into the writer with its integers in little-endian, encoded as given by the [`Config`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::serde::{to_writer_le_with, Config};
+/// use hyper_byte::writer::LittleWriter;
+/// use hyper_byte::writers::traits::ByteWriter;
+///
+/// let mut writer = LittleWriter::new();
+/// to_writer_le_with(&mut writer, &(300u32, "hi"), Config::VARINT).unwrap();
+/// assert_eq!(writer.to_vec(), [0xAC, 0x02, 2, b'h', b'i']);
+/// ```
+pub fn to_writer_le_with<W: LittleEndianByteWriter + ?Sized, T: Serialize + ?Sized>(
+    writer: &mut W,
+    value: &T,
+    config: Config,
+) -> Result<(), ByteError> {
+    to_writer::<LittleEndian, W, T>(writer, value, config)
+}
+
+/// Deserializes a value written by [`to_vec_be`] or [`to_writer_be`] from the reader, leaving it after the value.<br/>
+/// Strings and byte arrays are copied, as the reader cannot lend out its bytes, and bytes ending early result
+/// in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::reader::NetworkReader;
+/// use hyper_byte::readers::traits::BigEndianByteReader;
+/// use hyper_byte::serde::from_reader_be;
+///
+/// let mut reader = NetworkReader::new(&[0, 1, 0, 0, 0, 2, b'h', b'i', 9]);
+/// let value: (u16, String) = from_reader_be(&mut reader).unwrap();
+/// assert_eq!(value, (1, String::from("hi")));
+/// assert_eq!(reader.read_u8_be(), 9);
+/// ```
+pub fn from_reader_be<R: BigEndianByteReader + ?Sized, T: DeserializeOwned>(reader: &mut R) -> Result<T, ByteError> {
+    from_reader::<BigEndian, R, T>(reader, Config::default())
+}
+
+/// Deserializes a value written by [`to_vec_le`] or [`to_writer_le`] from the reader, leaving it after the value.<br/>
+/// Strings and byte arrays are copied, as the reader cannot lend out its bytes, and bytes ending early result
+/// in [`ByteError::UnexpectedEnd`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::reader::LittleReader;
+/// use hyper_byte::serde::from_reader_le;
+///
+/// let mut reader = LittleReader::new(&[1, 0, 2, 0, 0, 0, b'h', b'i']);
+/// let value: (u16, String) = from_reader_le(&mut reader).unwrap();
+/// assert_eq!(value, (1, String::from("hi")));
+/// ```
+pub fn from_reader_le<R: LittleEndianByteReader + ?Sized, T: DeserializeOwned>(reader: &mut R) -> Result<T, ByteError> {
+    from_reader::<LittleEndian, R, T>(reader, Config::default())
+}
+
+/// Deserializes a value written by [`to_writer_be_with`] with the same [`Config`] from the reader, leaving it
+/// after the value
+///
+/// # Examples
+/// ```
+/// use hyper_byte::reader::NetworkReader;
+/// use hyper_byte::serde::{from_reader_be_with, Config};
+///
+/// let mut reader = NetworkReader::new(&[0xAC, 0x02, 2, b'h', b'i']);
+/// let value: (u32, String) = from_reader_be_with(&mut reader, Config::VARINT).unwrap();
+/// assert_eq!(value, (300, String::from("hi")));
+/// ```
+pub fn from_reader_be_with<R: BigEndianByteReader + ?Sized, T: DeserializeOwned>(
+    reader: &mut R,
+    config: Config,
+) -> Result<T, ByteError> {
+    from_reader::<BigEndian, R, T>(reader, config)
+}
+
+/// Deserializes a value written by [`to_writer_le_with`] with the same [`Config`] from the reader, leaving it
+/// after the value
+///
+/// # Examples
+/// ```
+/// use hyper_byte::reader::LittleReader;
+/// use hyper_byte::serde::{from_reader_le_with, Config};
+///
+/// let mut reader = LittleReader::new(&[0xAC, 0x02, 2, b'h', b'i']);
+/// let value: (u32, String) = from_reader_le_with(&mut reader, Config::VARINT).unwrap();
+/// assert_eq!(value, (300, String::from("hi")));
+/// ```
+pub fn from_reader_le_with<R: LittleEndianByteReader + ?Sized, T: DeserializeOwned>(
+    reader: &mut R,
+    config: Config,
+) -> Result<T, ByteError> {
+    from_reader::<LittleEndian, R, T>(reader, config)
+}
+
+fn to_vec<E, T: Serialize + ?Sized>(value: &T, config: Config) -> Result<Vec<u8>, ByteError>
+where
+    FastByteWriter: EndianWriter<E>,
+{
+    let mut writer = FastByteWriter::new();
+    to_writer::<E, _, T>(&mut writer, value, config)?;
+    Ok(writer.to_vec())
+}
+
+fn to_writer<E, W: EndianWriter<E> + ?Sized, T: Serialize + ?Sized>(
+    writer: &mut W,
+    value: &T,
+    config: Config,
+) -> Result<(), ByteError> {
+    value.serialize(&mut Serializer { writer, config, endian: PhantomData })
+}
+
+fn from_slice<'de, E, T: Deserialize<'de>>(bytes: &'de [u8], config: Config) -> Result<T, ByteError>
+where
+    FastByteReader<'de>: EndianReader<E>,
+{
+    let mut deserializer = Deserializer::new(SliceInput(FastByteReader::new(bytes)), config);
+    T::deserialize(&mut deserializer)
+}
+
+fn from_reader<E, R: EndianReader<E> + ?Sized, T: DeserializeOwned>(
+    reader: &mut R,
+    config: Config,
+) -> Result<T, ByteError> {
+    let mut deserializer = Deserializer::new(ReaderInput(reader), config);
+    T::deserialize(&mut deserializer)
+}
+
+struct Serializer<'a, W: ?Sized, E> {
+    writer: &'a mut W,
+    config: Config,
+    endian: PhantomData<E>,
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> Serializer<'_, W, E> {
+    /// Writes an unsigned integer of at most 64 bits, fixed at the width of `fixed` or as a varint
+    fn write_unsigned(&mut self, value: u64, fixed: fn(&mut W, u64)) {
+        match self.config.integers {
+            IntEncoding::Fixed => fixed(self.writer, value),
+            IntEncoding::Varint => self.writer.write_varint_u64(value),
+        }
+    }
+
+    /// Writes a signed integer of at most 64 bits, fixed at the width of `fixed` or as a zigzag varint
+    fn write_signed(&mut self, value: i64, fixed: fn(&mut W, i64)) {
+        match self.config.integers {
+            IntEncoding::Fixed => fixed(self.writer, value),
+            IntEncoding::Varint => self.writer.write_varint_i64(value),
+        }
+    }
+
+    fn write_length(&mut self, length: usize) -> Result<(), ByteError> {
+        let maximum = self.config.lengths.maximum();
+        if length > maximum {
+            return Err(ByteError::TooLong { length, maximum });
+        }
+        match self.config.lengths {
+            LengthEncoding::U8 => self.writer.write_u8(length as u8),
+            LengthEncoding::U16 => self.writer.write_u16(length as u16),
+            LengthEncoding::U32 => self.writer.write_u32(length as u32),
+            LengthEncoding::U64 => self.writer.write_u64(length as u64),
+            LengthEncoding::Varint => self.writer.write_varint_u64(length as u64),
+        }
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), ByteError> {
+        self.write_length(bytes.len())?;
+        self.writer.put_bytes(bytes);
+        Ok(())
+    }
+
+    fn write_variant(&mut self, index: u32) {
+        self.write_unsigned(index as u64, |writer, value| writer.write_u32(value as u32));
+    }
+}
+
+/// Serializes sequences, tuples, maps, structs and their variants, which are all their elements in order
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeSeq for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeTuple for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeTupleStruct for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeTupleVariant for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeMap for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ByteError> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeStruct for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::SerializeStructVariant for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<(), ByteError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+}
+
+impl<W: EndianWriter<E> + ?Sized, E> ser::Serializer for &mut Serializer<'_, W, E> {
+    type Ok = ();
+    type Error = ByteError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, value: bool) -> Result<(), ByteError> {
+        self.writer.write_bool(value);
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), ByteError> {
+        self.writer.write_i8(value);
+        Ok(())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), ByteError> {
+        self.write_signed(value as i64, |writer, value| writer.write_u16(value as u16));
+        Ok(())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), ByteError> {
+        self.write_signed(value as i64, |writer, value| writer.write_u32(value as u32));
+        Ok(())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<(), ByteError> {
+        self.write_signed(value, |writer, value| writer.write_u64(value as u64));
+        Ok(())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<(), ByteError> {
+        self.writer.write_i128(value);
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), ByteError> {
+        self.writer.write_u8(value);
+        Ok(())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), ByteError> {
+        self.write_unsigned(value as u64, |writer, value| writer.write_u16(value as u16));
+        Ok(())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), ByteError> {
+        self.write_unsigned(value as u64, |writer, value| writer.write_u32(value as u32));
+        Ok(())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<(), ByteError> {
+        self.write_unsigned(value, |writer, value| writer.write_u64(value));
+        Ok(())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<(), ByteError> {
+        self.writer.write_u128(value);
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), ByteError> {
+        self.writer.write_f32(value);
+        Ok(())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), ByteError> {
+        self.writer.write_f64(value);
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), ByteError> {
+        self.serialize_u32(value as u32)
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), ByteError> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), ByteError> {
+        self.write_bytes(value)
+    }
+
+    fn serialize_none(self) -> Result<(), ByteError> {
+        self.writer.write_bool(false);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), ByteError> {
+        self.writer.write_bool(true);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), ByteError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, index: u32, _: &'static str) -> Result<(), ByteError> {
+        self.write_variant(index);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<(), ByteError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        index: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), ByteError> {
+        self.write_variant(index);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<Self, ByteError> {
+        let length = length.ok_or_else(|| ser::Error::custom("sequences must know their length up front"))?;
+        self.write_length(length)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, ByteError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, ByteError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, index: u32, _: &'static str, _: usize) -> Result<Self, ByteError> {
+        self.write_variant(index);
+        Ok(self)
+    }
+
+    fn serialize_map(self, length: Option<usize>) -> Result<Self, ByteError> {
+        let length = length.ok_or_else(|| ser::Error::custom("maps must know their length up front"))?;
+        self.write_length(length)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, ByteError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, index: u32, _: &'static str, _: usize) -> Result<Self, ByteError> {
+        self.write_variant(index);
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// A string or byte array, lent out of the input or copied out of a reader which cannot lend it
+enum Slice<'de, 's, T: ?Sized> {
+    Borrowed(&'de T),
+    Copied(&'s T),
+}
+
+/// Where the deserializer reads from, in the byte order `E`
+trait Input<'de, E> {
+    type Reader: EndianReader<E> + ?Sized;
+
+    fn reader(&mut self) -> &mut Self::Reader;
+
+    /// Reads `length` bytes, lent out of the input where it can or else copied into the scratch buffer
+    fn read_bytes<'s>(&mut self, length: usize, scratch: &'s mut Vec<u8>) -> Result<Slice<'de, 's, [u8]>, ByteError>;
+}
+
+/// Reads a slice, lending out the bytes of strings and byte arrays
+struct SliceInput<'de>(FastByteReader<'de>);
+
+impl<'de, E> Input<'de, E> for SliceInput<'de>
+where
+    FastByteReader<'de>: EndianReader<E>,
+{
+    type Reader = FastByteReader<'de>;
+
+    fn reader(&mut self) -> &mut FastByteReader<'de> {
+        &mut self.0
+    }
+
+    fn read_bytes<'s>(&mut self, length: usize, _: &'s mut Vec<u8>) -> Result<Slice<'de, 's, [u8]>, ByteError> {
+        let bytes = self.0.remaining();
+        if bytes.len() < length {
+            return Err(ByteError::UnexpectedEnd { needed: length, remaining: bytes.len() });
+        }
+        self.0.skip_n(length);
+        Ok(Slice::Borrowed(&bytes[..length]))
+    }
+}
+
+/// Reads any reader, copying the bytes of strings and byte arrays a piece at a time as it hands them out
+struct ReaderInput<'a, R: ?Sized>(&'a mut R);
+
+impl<'de, R: EndianReader<E> + ?Sized, E> Input<'de, E> for ReaderInput<'_, R> {
+    type Reader = R;
+
+    fn reader(&mut self) -> &mut R {
+        self.0
+    }
+
+    fn read_bytes<'s>(&mut self, length: usize, scratch: &'s mut Vec<u8>) -> Result<Slice<'de, 's, [u8]>, ByteError> {
+        scratch.clear();
+        while scratch.len() < length {
+            let bytes = self.0.byte_array();
+            if bytes.is_empty() {
+                return Err(ByteError::UnexpectedEnd { needed: length, remaining: scratch.len() });
+            }
+            let amount = bytes.len().min(length - scratch.len());
+            scratch.extend_from_slice(&bytes[..amount]);
+            self.0.skip_n(amount);
+        }
+        Ok(Slice::Copied(scratch))
+    }
+}
+
+struct Deserializer<'de, I, E> {
+    input: I,
+    scratch: Vec<u8>,
+    config: Config,
+    endian: PhantomData<(&'de (), E)>,
+}
+
+impl<'de, I: Input<'de, E>, E> Deserializer<'de, I, E> {
+    fn new(input: I, config: Config) -> Self {
+        Self { input, scratch: Vec::new(), config, endian: PhantomData }
+    }
+
+    fn reader(&mut self) -> &mut I::Reader {
+        self.input.reader()
+    }
+
+    /// Checks that `needed` more bytes are left, before a read which would panic without them
+    fn ensure(&mut self, needed: usize) -> Result<&mut I::Reader, ByteError> {
+        self.ensure_with(|_| needed)
+    }
+
+    /// Checks that the bytes `needed` works out from those left are there, asking the reader again while it
+    /// keeps handing out more, as one reading from a stream only fills what it has been given so far
+    fn ensure_with(&mut self, needed: impl Fn(&[u8]) -> usize) -> Result<&mut I::Reader, ByteError> {
+        let mut remaining = usize::MAX;
+        loop {
+            let bytes = self.reader().byte_array();
+            let needed = needed(bytes);
+            if bytes.len() >= needed {
+                return Ok(self.reader());
+            }
+            if bytes.len() == remaining {
+                return Err(ByteError::UnexpectedEnd { needed, remaining });
+            }
+            remaining = bytes.len();
+        }
+    }
+
+    /// Checks that a whole varint is left, up to its terminating byte or the most it can take
+    fn ensure_varint(&mut self) -> Result<&mut I::Reader, ByteError> {
+        self.ensure_with(|bytes| {
+            let terminator = bytes.iter().take(10).position(|byte| byte & 0x80 == 0);
+            terminator.map_or(bytes.len().min(9) + 1, |position| position + 1)
+        })
+    }
+
+    /// Reads an unsigned integer of at most 64 bits, fixed at the width of `fixed` or as a varint
+    fn read_unsigned<T: TryFrom<u64>>(&mut self, fixed: fn(&mut I::Reader) -> T) -> Result<T, ByteError> {
+        match self.config.integers {
+            IntEncoding::Fixed => Ok(fixed(self.ensure(size_of::<T>())?)),
+            IntEncoding::Varint => {
+                T::try_from(self.ensure_varint()?.read_varint_u64()?).map_err(|_| ByteError::OutOfRange)
+            }
+        }
+    }
+
+    /// Reads a signed integer of at most 64 bits, fixed at the width of `fixed` or as a zigzag varint
+    fn read_signed<T: TryFrom<i64>>(&mut self, fixed: fn(&mut I::Reader) -> T) -> Result<T, ByteError> {
+        match self.config.integers {
+            IntEncoding::Fixed => Ok(fixed(self.ensure(size_of::<T>())?)),
+            IntEncoding::Varint => {
+                T::try_from(self.ensure_varint()?.read_varint_i64()?).map_err(|_| ByteError::OutOfRange)
+            }
+        }
+    }
+
+    fn read_length(&mut self) -> Result<usize, ByteError> {
+        let length = match self.config.lengths {
+            LengthEncoding::U8 => self.ensure(1)?.read_u8() as u64,
+            LengthEncoding::U16 => self.ensure(2)?.read_u16() as u64,
+            LengthEncoding::U32 => self.ensure(4)?.read_u32() as u64,
+            LengthEncoding::U64 => self.ensure(8)?.read_u64(),
+            LengthEncoding::Varint => self.ensure_varint()?.read_varint_u64()?,
+        };
+        usize::try_from(length).map_err(|_| ByteError::OutOfRange)
+    }
+
+    /// Reads a length prefix and that many bytes, borrowed rather than copied where the input allows
+    fn read_bytes(&mut self) -> Result<Slice<'de, '_, [u8]>, ByteError> {
+        let length = self.read_length()?;
+        self.input.read_bytes(length, &mut self.scratch)
+    }
+
+    fn read_str(&mut self) -> Result<Slice<'de, '_, str>, ByteError> {
+        fn from_utf8(bytes: &[u8]) -> Result<&str, ByteError> {
+            core::str::from_utf8(bytes).map_err(|error| ByteError::InvalidUtf8 { position: error.valid_up_to() })
+        }
+        Ok(match self.read_bytes()? {
+            Slice::Borrowed(bytes) => Slice::Borrowed(from_utf8(bytes)?),
+            Slice::Copied(bytes) => Slice::Copied(from_utf8(bytes)?),
+        })
+    }
+
+    fn read_variant(&mut self) -> Result<u32, ByteError> {
+        self.read_unsigned(|reader| reader.read_u32())
+    }
+
+    fn read_byte(&mut self) -> Result<u8, ByteError> {
+        Ok(self.ensure(1)?.read_u8())
+    }
+
+    fn read_bool(&mut self) -> Result<bool, ByteError> {
+        self.ensure(1)?.read_bool()
+    }
+}
+
+/// Gives the visitor of a sequence, tuple, struct or map the amount of elements read or known up front
+struct Elements<'a, 'de, I, E> {
+    deserializer: &'a mut Deserializer<'de, I, E>,
+    remaining: usize,
+}
+
+impl<'de, I: Input<'de, E>, E> de::SeqAccess<'de> for Elements<'_, 'de, I, E> {
+    type Error = ByteError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, ByteError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, I: Input<'de, E>, E> de::MapAccess<'de> for Elements<'_, 'de, I, E> {
+    type Error = ByteError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, ByteError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, ByteError> {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, I: Input<'de, E>, E> de::EnumAccess<'de> for &mut Deserializer<'de, I, E> {
+    type Error = ByteError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), ByteError> {
+        let index = self.read_variant()?;
+        let value = seed.deserialize(IntoDeserializer::<ByteError>::into_deserializer(index))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, I: Input<'de, E>, E> de::VariantAccess<'de> for &mut Deserializer<'de, I, E> {
+    type Error = ByteError;
+
+    fn unit_variant(self) -> Result<(), ByteError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, ByteError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, length: usize, visitor: V) -> Result<V::Value, ByteError> {
+        de::Deserializer::deserialize_tuple(self, length, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, ByteError> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
+
+impl<'de, I: Input<'de, E>, E> de::Deserializer<'de> for &mut Deserializer<'de, I, E> {
+    type Error = ByteError;
+
+    /// The format does not describe itself, so the type must be known
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ByteError> {
+        Err(de::Error::custom("the format is not self-describing, so the type must be known"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_bool(self.read_bool()?)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_i8(self.read_byte()? as i8)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_i16(self.read_signed(|reader| reader.read_u16() as i16)?)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_i32(self.read_signed(|reader| reader.read_u32() as i32)?)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_i64(self.read_signed(|reader| reader.read_u64() as i64)?)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_i128(self.ensure(16)?.read_i128())
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u8(self.read_byte()?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u16(self.read_unsigned(|reader| reader.read_u16())?)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u32(self.read_unsigned(|reader| reader.read_u32())?)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u64(self.read_unsigned(|reader| reader.read_u64())?)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u128(self.ensure(16)?.read_u128())
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_f32(self.ensure(4)?.read_f32())
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_f64(self.ensure(8)?.read_f64())
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        let value = self.read_unsigned(|reader| reader.read_u32())?;
+        visitor.visit_char(char::from_u32(value).ok_or(ByteError::InvalidChar { value })?)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        match self.read_str()? {
+            Slice::Borrowed(value) => visitor.visit_borrowed_str(value),
+            Slice::Copied(value) => visitor.visit_str(value),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        match self.read_bytes()? {
+            Slice::Borrowed(value) => visitor.visit_borrowed_bytes(value),
+            Slice::Copied(value) => visitor.visit_bytes(value),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        if self.read_bool()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        let remaining = self.read_length()?;
+        visitor.visit_seq(Elements { deserializer: self, remaining })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, length: usize, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_seq(Elements { deserializer: self, remaining: length })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        length: usize,
+        visitor: V,
+    ) -> Result<V::Value, ByteError> {
+        self.deserialize_tuple(length, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        let remaining = self.read_length()?;
+        visitor.visit_map(Elements { deserializer: self, remaining })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ByteError> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ByteError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ByteError> {
+        visitor.visit_u32(self.read_variant()?)
+    }
+
+    /// Nothing can be skipped without knowing its type
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ByteError> {
+        Err(de::Error::custom("the format is not self-describing, so nothing can be ignored"))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
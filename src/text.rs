@@ -0,0 +1,25 @@
+//! Reading UTF-8 text, shared by the codec traits and the derived implementations.
+
+use alloc::string::String;
+use crate::error::ByteError;
+use crate::readers::traits::ByteReader;
+
+/// Reads `length` bytes of UTF-8 into a [`String`].<br/>
+/// Invalid UTF-8 results in an error, though the bytes are still consumed</br>
+/// Panics if there is not enough space
+#[inline(always)]
+pub(crate) fn read_string<R: ByteReader + ?Sized>(reader: &mut R, length: usize) -> Result<String, ByteError> {
+    let byte_array = reader.byte_array();
+    if byte_array.len() < length {
+        panic!("Attempted to read a string of an array without enough space within the array.");
+    }
+    let new_length = byte_array.len() - length;
+    let string = core::str::from_utf8(&byte_array[..length])
+        .map(String::from)
+        .map_err(|error| ByteError::InvalidUtf8 { position: error.valid_up_to() });
+    // SAFETY: The length was checked against the array above
+    unsafe {
+        reader.advance(length, new_length);
+    }
+    string
+}
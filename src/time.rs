@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+//! Conversions between [`SystemTime`] and the epochs used by the binary time encodings.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::error::ByteError;
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch
+const NTP_UNIX_OFFSET: u64 = 2_208_988_800;
+/// Seconds between the FILETIME epoch (1601-01-01) and the Unix epoch
+const FILETIME_UNIX_OFFSET: u64 = 11_644_473_600;
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const FILETIME_TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// Builds a duration out of seconds and nanoseconds, failing on nanoseconds of a second or more
+#[inline(always)]
+pub(crate) fn duration(seconds: u64, nanos: u32) -> Result<Duration, ByteError> {
+    if nanos < NANOS_PER_SECOND {
+        Ok(Duration::new(seconds, nanos))
+    } else {
+        Err(ByteError::OutOfRange)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn from_unix(since_epoch: Duration) -> Result<SystemTime, ByteError> {
+    UNIX_EPOCH.checked_add(since_epoch).ok_or(ByteError::OutOfRange)
+}
+
+/// The time since the Unix epoch, failing for times before it
+#[inline(always)]
+pub(crate) fn to_unix(time: SystemTime) -> Result<Duration, ByteError> {
+    time.duration_since(UNIX_EPOCH).map_err(|_| ByteError::OutOfRange)
+}
+
+/// Converts NTP 32.32 fixed-point seconds since 1900 into a time
+#[inline(always)]
+pub(crate) fn from_ntp(timestamp: u64) -> Result<SystemTime, ByteError> {
+    let seconds = timestamp >> 32;
+    let nanos = ((timestamp & u32::MAX as u64) * NANOS_PER_SECOND as u64) >> 32;
+    let since_ntp_epoch = Duration::new(seconds, nanos as u32);
+    let ntp_epoch = UNIX_EPOCH
+        .checked_sub(Duration::from_secs(NTP_UNIX_OFFSET))
+        .ok_or(ByteError::OutOfRange)?;
+    ntp_epoch.checked_add(since_ntp_epoch).ok_or(ByteError::OutOfRange)
+}
+
+/// Converts a time into NTP 32.32 fixed-point seconds since 1900, failing outside of era 0 (1900 to 2036)
+#[inline(always)]
+pub(crate) fn to_ntp(time: SystemTime) -> Result<u64, ByteError> {
+    let ntp_epoch = UNIX_EPOCH
+        .checked_sub(Duration::from_secs(NTP_UNIX_OFFSET))
+        .ok_or(ByteError::OutOfRange)?;
+    let since_ntp_epoch = time.duration_since(ntp_epoch).map_err(|_| ByteError::OutOfRange)?;
+    let seconds = u32::try_from(since_ntp_epoch.as_secs()).map_err(|_| ByteError::OutOfRange)?;
+    let fraction = ((since_ntp_epoch.subsec_nanos() as u64) << 32) / NANOS_PER_SECOND as u64;
+    Ok(((seconds as u64) << 32) | fraction)
+}
+
+/// Converts Windows FILETIME 100 nanosecond ticks since 1601 into a time
+#[inline(always)]
+pub(crate) fn from_filetime(ticks: u64) -> Result<SystemTime, ByteError> {
+    let since_filetime_epoch = Duration::new(
+        ticks / FILETIME_TICKS_PER_SECOND,
+        (ticks % FILETIME_TICKS_PER_SECOND) as u32 * 100,
+    );
+    let filetime_epoch = UNIX_EPOCH
+        .checked_sub(Duration::from_secs(FILETIME_UNIX_OFFSET))
+        .ok_or(ByteError::OutOfRange)?;
+    filetime_epoch.checked_add(since_filetime_epoch).ok_or(ByteError::OutOfRange)
+}
+
+/// Converts a time into Windows FILETIME 100 nanosecond ticks since 1601, failing for times before 1601
+#[inline(always)]
+pub(crate) fn to_filetime(time: SystemTime) -> Result<u64, ByteError> {
+    let filetime_epoch = UNIX_EPOCH
+        .checked_sub(Duration::from_secs(FILETIME_UNIX_OFFSET))
+        .ok_or(ByteError::OutOfRange)?;
+    let since_filetime_epoch = time.duration_since(filetime_epoch).map_err(|_| ByteError::OutOfRange)?;
+    u64::try_from(since_filetime_epoch.as_nanos() / 100).map_err(|_| ByteError::OutOfRange)
+}
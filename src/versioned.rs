@@ -0,0 +1,136 @@
+//! Versioned sections, for save files and messages whose layout changes over time.<br/>
+//! Each section is a `u16` version and a `u32` length, in the byte order `E`, followed by the body. Reading
+//! dispatches on the version to the decoder registered for it, and then skips to the end of the section,
+//! so bytes a newer writer appended to the body are passed over and older readers can still read it.
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::endian::BigEndian;
+//! use hyper_byte::reader::NetworkReader;
+//! use hyper_byte::versioned::{write_versioned, Versions};
+//! use hyper_byte::writer::NetworkWriter;
+//! use hyper_byte::writers::traits::ByteWriter;
+//!
+//! #[derive(PartialEq, Debug)]
+//! struct Save {
+//!     level: u32,
+//!     name: String,
+//! }
+//!
+//! // Version 1 only held the level, and version 2 added the name
+//! let versions = Versions::new()
+//!     .register_decode::<BigEndian, u32>(1, |level| Save { level, name: String::new() })
+//!     .register_decode::<BigEndian, (u32, String)>(2, |(level, name)| Save { level, name });
+//!
+//! let mut writer = NetworkWriter::new();
+//! write_versioned::<BigEndian, _, _>(&mut writer, 1, &7u32).unwrap();
+//! write_versioned::<BigEndian, _, _>(&mut writer, 2, &(8u32, String::from("Ann"))).unwrap();
+//! // A newer writer which also added a score
+//! write_versioned::<BigEndian, _, _>(&mut writer, 3, &(9u32, String::from("Bob"), 1.5f64)).unwrap();
+//!
+//! let buffer = writer.to_vec();
+//! let mut reader = NetworkReader::new(&buffer);
+//! assert_eq!(versions.read(&mut reader).unwrap(), Save { level: 7, name: String::new() });
+//! assert_eq!(versions.read(&mut reader).unwrap(), Save { level: 8, name: String::from("Ann") });
+//! // Read with the version 2 decoder, skipping the score
+//! assert_eq!(versions.read(&mut reader).unwrap(), Save { level: 9, name: String::from("Bob") });
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use crate::codec::{HyperDecode, HyperEncode};
+use crate::endian::{EndianReader, EndianWriter};
+use crate::error::ByteError;
+use crate::reader::FastByteReader;
+use crate::writer::SizeCounter;
+
+/// Writes the section header, a `u16` version and the `u32` length of the body, in the byte order `E`.<br/>
+/// A body longer than a `u32` allows results in [`ByteError::TooLong`], without writing anything
+pub fn write_header<E, W: EndianWriter<E> + ?Sized>(writer: &mut W, version: u16, length: usize) -> Result<(), ByteError> {
+    let length = u32::try_from(length).map_err(|_| ByteError::TooLong { length, maximum: u32::MAX as usize })?;
+    writer.write_u16(version);
+    writer.write_u32(length);
+    Ok(())
+}
+
+/// Writes the value as a section of the given version, in the byte order `E`.<br/>
+/// The length of the body is found with [`HyperEncode::encoded_len`] first, so the writer only grows once
+pub fn write_versioned<E, W: EndianWriter<E> + ?Sized, T: HyperEncode<E> + ?Sized>(
+    writer: &mut W,
+    version: u16,
+    value: &T,
+) -> Result<(), ByteError>
+where
+    SizeCounter: EndianWriter<E>,
+{
+    write_header(writer, version, value.encoded_len()?)?;
+    value.encode(writer)
+}
+
+/// Reads a section header, returning the version and the length of the body, in the byte order `E`</br>
+/// Panics if there is not enough space
+pub fn read_header<E, R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<(u16, usize), ByteError> {
+    let version = reader.read_u16();
+    let length = usize::try_from(reader.read_u32()).map_err(|_| ByteError::OutOfRange)?;
+    Ok((version, length))
+}
+
+/// Decodes the body of a section into a `T`, given the bytes of the body only
+pub type Decoder<T> = Box<dyn Fn(&[u8]) -> Result<T, ByteError>>;
+
+/// The decoders of each version of a section, all producing a `T`
+pub struct Versions<T> {
+    decoders: BTreeMap<u16, Decoder<T>>,
+}
+
+impl<T> Default for Versions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Versions<T> {
+    /// Versions without any decoders registered yet
+    pub const fn new() -> Self {
+        Self { decoders: BTreeMap::new() }
+    }
+
+    /// Registers the decoder for the version, replacing any registered before.<br/>
+    /// The decoder is given the bytes of the body, which may be followed by more than it knows about
+    pub fn register(mut self, version: u16, decoder: impl Fn(&[u8]) -> Result<T, ByteError> + 'static) -> Self {
+        self.decoders.insert(version, Box::new(decoder));
+        self
+    }
+
+    /// Registers decoding the body as a `V` in the byte order `E`, converted into a `T` with `convert`
+    pub fn register_decode<E: 'static, V: HyperDecode<E> + 'static>(self, version: u16, convert: impl Fn(V) -> T + 'static) -> Self
+    where
+        for<'a> FastByteReader<'a>: EndianReader<E>,
+    {
+        self.register(version, move |bytes| EndianReader::<E>::decode::<V>(&mut FastByteReader::new(bytes)).map(&convert))
+    }
+
+    /// The versions with a decoder registered, from oldest to newest
+    pub fn versions(&self) -> impl Iterator<Item = u16> + '_ {
+        self.decoders.keys().copied()
+    }
+
+    /// Reads a section in the byte order `E`, decoding it with the decoder of its version.<br/>
+    /// A version without a decoder of its own uses the newest one before it, as a newer writer only appends to
+    /// the body, and a version older than every decoder results in [`ByteError::UnknownTag`].<br/>
+    /// The reader is always left at the end of the section, skipping whatever the decoder did not read</br>
+    /// Panics if there is not enough space
+    pub fn read<E, R: EndianReader<E> + ?Sized>(&self, reader: &mut R) -> Result<T, ByteError> {
+        let (version, length) = read_header(reader)?;
+        let byte_array = reader.byte_array();
+        if byte_array.len() < length {
+            panic!("Attempted to read a section of an array without enough space within the array.");
+        }
+        let value = match self.decoders.range(..=version).next_back() {
+            Some((_, decoder)) => decoder(&byte_array[..length]),
+            None => Err(ByteError::UnknownTag { tag: version as u64 }),
+        };
+        reader.skip_n(length);
+        value
+    }
+}
@@ -0,0 +1,222 @@
+//! Reinterpreting bytes as a struct in place, without copying, such as the header of a memory mapped file.<br/>
+//! The struct is `#[repr(C)]` and built from the types of [`crate::wire`], bytes and other views, so it has
+//! an alignment of 1, no padding, and accepts any bytes. With the `derive` feature, `#[derive(View)]` checks
+//! this and implements [`View`].
+//!
+//! # Examples
+//! ```
+//! use hyper_byte::view::{view, view_prefix, view_slice, View};
+//! use hyper_byte::wire::{F32Le, U16Be, U32Be};
+//!
+//! #[repr(C)]
+//! struct Header {
+//!     magic: [u8; 4],
+//!     version: U16Be,
+//!     count: U16Be,
+//! }
+//!
+//! #[repr(C)]
+//! struct Entry {
+//!     id: U32Be,
+//!     weight: F32Le,
+//! }
+//!
+//! // SAFETY: Both are `#[repr(C)]` with every field a `View`
+//! unsafe impl View for Header {}
+//! unsafe impl View for Entry {}
+//!
+//! let bytes = [b'H', b'Y', b'P', b'R', 0, 1, 0, 2, 0, 0, 0, 7, 0, 0, 0xC0, 0x3F, 0, 0, 0, 8, 0, 0, 0x80, 0x3F];
+//!
+//! let (header, rest) = view_prefix::<Header>(&bytes).unwrap();
+//! assert_eq!(header.magic, *b"HYPR");
+//! assert_eq!(header.version.get(), 1);
+//!
+//! let entries = view_slice::<Entry>(rest).unwrap();
+//! assert_eq!(entries.len(), header.count.get() as usize);
+//! assert_eq!(entries[0].id.get(), 7);
+//! assert_eq!(entries[1].weight.get(), 1.0);
+//!
+//! assert!(view::<Header>(&bytes).is_err());
+//! ```
+
+use core::mem::{align_of, size_of};
+use core::slice;
+use crate::error::ByteError;
+use crate::wire::*;
+
+/// A type which can be viewed in place over any bytes
+///
+/// # Safety
+/// The type must have an alignment of 1, no padding, and every bit pattern of its size must be a valid value.<br/>
+/// A `#[repr(C)]` struct of which every field is a [`View`] upholds all three.
+pub unsafe trait View: Sized {}
+
+/// Derives [`View`] for a `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]` struct, checking at
+/// compile time that every field is a [`View`] too.
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::{view, View};
+/// use hyper_byte::wire::{I16Le, U32Be};
+///
+/// #[derive(View)]
+/// #[repr(C)]
+/// struct Record {
+///     id: U32Be,
+///     offsets: [I16Le; 2],
+///     flags: u8,
+/// }
+///
+/// let record = view::<Record>(&[0, 0, 1, 0, 0xFF, 0xFF, 2, 0, 3]).unwrap();
+/// assert_eq!(record.id.get(), 256);
+/// assert_eq!(record.offsets[0].get(), -1);
+/// assert_eq!(record.flags, 3);
+/// ```
+#[cfg(feature = "derive")]
+pub use hyper_byte_derive::View;
+
+unsafe impl View for u8 {}
+unsafe impl View for i8 {}
+unsafe impl<T: View, const N: usize> View for [T; N] {}
+unsafe impl View for U16Be {}
+unsafe impl View for U16Le {}
+unsafe impl View for U32Be {}
+unsafe impl View for U32Le {}
+unsafe impl View for U64Be {}
+unsafe impl View for U64Le {}
+unsafe impl View for U128Be {}
+unsafe impl View for U128Le {}
+unsafe impl View for I16Be {}
+unsafe impl View for I16Le {}
+unsafe impl View for I32Be {}
+unsafe impl View for I32Le {}
+unsafe impl View for I64Be {}
+unsafe impl View for I64Le {}
+unsafe impl View for I128Be {}
+unsafe impl View for I128Le {}
+unsafe impl View for F32Be {}
+unsafe impl View for F32Le {}
+unsafe impl View for F64Be {}
+unsafe impl View for F64Le {}
+
+/// Checks the length of the bytes is exactly `expected`
+#[inline(always)]
+fn check_length(expected: usize, actual: usize) -> Result<(), ByteError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ByteError::LengthMismatch { expected, actual })
+    }
+}
+
+/// Views the bytes as a `T`, which must be exactly as long as the bytes.<br/>
+/// Any other length results in [`ByteError::LengthMismatch`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::view;
+/// use hyper_byte::wire::U32Le;
+///
+/// let value = view::<[U32Le; 2]>(&[1, 0, 0, 0, 2, 0, 0, 0]).unwrap();
+/// assert_eq!(value[1].get(), 2);
+/// ```
+#[inline(always)]
+pub fn view<T: View>(bytes: &[u8]) -> Result<&T, ByteError> {
+    const { assert!(align_of::<T>() == 1, "a View must have an alignment of 1") };
+    check_length(size_of::<T>(), bytes.len())?;
+    // SAFETY: T accepts any bytes and has an alignment of 1, and the length was checked above
+    Ok(unsafe { &*(bytes.as_ptr() as *const T) })
+}
+
+/// Views the bytes as a mutable `T`, which must be exactly as long as the bytes, so fields can be set in place.<br/>
+/// Any other length results in [`ByteError::LengthMismatch`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::view_mut;
+/// use hyper_byte::wire::U16Be;
+///
+/// let mut bytes = [0, 1];
+/// view_mut::<U16Be>(&mut bytes).unwrap().set(0x0203);
+/// assert_eq!(bytes, [2, 3]);
+/// ```
+#[inline(always)]
+pub fn view_mut<T: View>(bytes: &mut [u8]) -> Result<&mut T, ByteError> {
+    const { assert!(align_of::<T>() == 1, "a View must have an alignment of 1") };
+    check_length(size_of::<T>(), bytes.len())?;
+    // SAFETY: T accepts any bytes and has an alignment of 1, and the length was checked above
+    Ok(unsafe { &mut *(bytes.as_mut_ptr() as *mut T) })
+}
+
+/// Views the start of the bytes as a `T`, returning it along with the bytes after it.<br/>
+/// Bytes shorter than `T` result in [`ByteError::LengthMismatch`]
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::view_prefix;
+/// use hyper_byte::wire::U16Be;
+///
+/// let (value, rest) = view_prefix::<U16Be>(&[0, 1, 2]).unwrap();
+/// assert_eq!(value.get(), 1);
+/// assert_eq!(rest, [2]);
+/// ```
+#[inline(always)]
+pub fn view_prefix<T: View>(bytes: &[u8]) -> Result<(&T, &[u8]), ByteError> {
+    if bytes.len() < size_of::<T>() {
+        return Err(ByteError::LengthMismatch { expected: size_of::<T>(), actual: bytes.len() });
+    }
+    let (value, rest) = bytes.split_at(size_of::<T>());
+    Ok((view(value)?, rest))
+}
+
+/// Views the bytes as a slice of `T`, which the bytes must be a whole amount of.<br/>
+/// Any other length results in [`ByteError::LengthMismatch`], expecting the length rounded down to whole elements
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::view_slice;
+/// use hyper_byte::wire::I16Be;
+///
+/// let values = view_slice::<I16Be>(&[0, 1, 0xFF, 0xFE]).unwrap();
+/// assert_eq!(values.iter().map(|value| value.get()).sum::<i16>(), -1);
+/// assert!(view_slice::<I16Be>(&[0, 1, 2]).is_err());
+/// ```
+#[inline(always)]
+pub fn view_slice<T: View>(bytes: &[u8]) -> Result<&[T], ByteError> {
+    const { assert!(align_of::<T>() == 1, "a View must have an alignment of 1") };
+    let length = slice_length::<T>(bytes.len())?;
+    // SAFETY: T accepts any bytes and has an alignment of 1, and the bytes hold exactly `length` of them
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, length) })
+}
+
+/// Views the bytes as a mutable slice of `T`, which the bytes must be a whole amount of.<br/>
+/// Any other length results in [`ByteError::LengthMismatch`], expecting the length rounded down to whole elements
+///
+/// # Examples
+/// ```
+/// use hyper_byte::view::view_slice_mut;
+/// use hyper_byte::wire::U16Le;
+///
+/// let mut bytes = [1, 0, 2, 0];
+/// for value in view_slice_mut::<U16Le>(&mut bytes).unwrap() {
+///     value.set(value.get() * 10);
+/// }
+/// assert_eq!(bytes, [10, 0, 20, 0]);
+/// ```
+#[inline(always)]
+pub fn view_slice_mut<T: View>(bytes: &mut [u8]) -> Result<&mut [T], ByteError> {
+    const { assert!(align_of::<T>() == 1, "a View must have an alignment of 1") };
+    let length = slice_length::<T>(bytes.len())?;
+    // SAFETY: T accepts any bytes and has an alignment of 1, and the bytes hold exactly `length` of them
+    Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, length) })
+}
+
+/// The amount of `T` in `bytes` bytes, which must be a whole amount
+#[inline(always)]
+fn slice_length<T>(bytes: usize) -> Result<usize, ByteError> {
+    if size_of::<T>() == 0 {
+        return check_length(0, bytes).map(|_| 0);
+    }
+    check_length(bytes - bytes % size_of::<T>(), bytes)?;
+    Ok(bytes / size_of::<T>())
+}
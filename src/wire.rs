@@ -0,0 +1,237 @@
+//! Numbers stored in a fixed byte order, such as [`U32Be`] or [`F64Le`], which have an alignment of 1.<br/>
+//! They can be the fields of a `#[repr(C)]` struct viewed in place over bytes with [`crate::view`],
+//! or of a protocol struct read and written with the reader and writer traits, converting to and from
+//! the native value with the `read_*` functions only when it is used. Comparing, hashing and formatting
+//! them works on the value rather than the bytes.
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use crate::codec::{HyperDecode, HyperEncode, LengthPrefix};
+use crate::derive::{HyperRead, HyperWrite};
+use crate::endian::{EndianReader, EndianWriter};
+use crate::error::ByteError;
+use crate::readers::traits::ByteReader;
+use crate::writers::traits::ByteWriter;
+use crate::{
+    read_f32_be, read_f32_le, read_f64_be, read_f64_le, read_i128_be, read_i128_le, read_i16_be,
+    read_i16_le, read_i32_be, read_i32_le, read_i64_be, read_i64_le, read_u128_be, read_u128_le,
+    read_u16_be, read_u16_le, read_u32_be, read_u32_le, read_u64_be, read_u64_le,
+};
+
+/// Defines a number stored in a fixed byte order, from its name, the native type with the article used
+/// before it, its size in bytes, and the `read_*` function and `to_*_bytes` method of the byte order
+macro_rules! wire_number {
+    (integer $name:ident, $article:literal $ty:ident, $size:literal, $read:ident, $to_bytes:ident, $order:literal) => {
+        wire_number!(
+            @common $name, $article $ty, $size, $read, $to_bytes, $order,
+            concat!("let mut value = ", stringify!($name), "::new(7);"),
+            "value.set(value.get() + 1);",
+            "",
+            "assert_eq!(value.get(), 8);",
+            concat!("assert_eq!(value.to_bytes(), 8", stringify!($ty), ".", stringify!($to_bytes), "());"),
+        );
+
+        impl $name {
+            /// Adds `rhs` to the value, returning `None` on overflow
+            #[inline(always)]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.get().checked_add(rhs.get()).map(Self::new)
+            }
+
+            /// Subtracts `rhs` from the value, returning `None` on overflow
+            #[inline(always)]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.get().checked_sub(rhs.get()).map(Self::new)
+            }
+
+            /// Multiplies the value by `rhs`, returning `None` on overflow
+            #[inline(always)]
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.get().checked_mul(rhs.get()).map(Self::new)
+            }
+
+            /// Divides the value by `rhs`, returning `None` if `rhs` is zero or on overflow
+            #[inline(always)]
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.get().checked_div(rhs.get()).map(Self::new)
+            }
+
+            /// Takes the remainder of the value divided by `rhs`, returning `None` if `rhs` is zero or on overflow
+            #[inline(always)]
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.get().checked_rem(rhs.get()).map(Self::new)
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl Ord for $name {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl Hash for $name {
+            #[inline(always)]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+    };
+    (float $name:ident, $article:literal $ty:ident, $size:literal, $read:ident, $to_bytes:ident, $order:literal) => {
+        wire_number!(
+            @common $name, $article $ty, $size, $read, $to_bytes, $order,
+            concat!("let mut value = ", stringify!($name), "::new(1.5);"),
+            "value.set(value.get() * 2.0);",
+            "",
+            "assert_eq!(value.get(), 3.0);",
+            concat!("assert_eq!(value.to_bytes(), 3.0", stringify!($ty), ".", stringify!($to_bytes), "());"),
+        );
+
+        impl PartialOrd for $name {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.get().partial_cmp(&other.get())
+            }
+        }
+    };
+    (@common $name:ident, $article:literal $ty:ident, $size:literal, $read:ident, $to_bytes:ident, $order:literal, $($example:expr),+ $(,)?) => {
+        #[doc = concat!($article, " `", stringify!($ty), "` stored in ", $order, " order, with an alignment of 1")]
+        ///
+        /// # Examples
+        /// ```
+        #[doc = concat!("use hyper_byte::wire::", stringify!($name), ";")]
+        ///
+        $(#[doc = $example])+
+        /// ```
+        #[derive(Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            #[doc = concat!("Stores the value in ", $order, " order")]
+            #[inline(always)]
+            pub const fn new(value: $ty) -> Self {
+                Self(value.$to_bytes())
+            }
+
+            #[doc = concat!("Wraps bytes which are already in ", $order, " order")]
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            /// The value in the native endian
+            #[inline(always)]
+            pub fn get(self) -> $ty {
+                // SAFETY: The array always holds exactly as many bytes as the value takes
+                unsafe { $read(&self.0) }
+            }
+
+            #[doc = concat!("Replaces the value, storing it in ", $order, " order")]
+            #[inline(always)]
+            pub fn set(&mut self, value: $ty) {
+                self.0 = value.$to_bytes();
+            }
+
+            #[doc = concat!("The bytes in ", $order, " order, as they are stored")]
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; $size] {
+                self.0
+            }
+        }
+
+        impl From<$ty> for $name {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $ty {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl<R: ByteReader + ?Sized> HyperRead<R> for $name {
+            #[inline(always)]
+            fn hyper_read(reader: &mut R) -> Result<Self, ByteError> {
+                Ok(reader.read_wire())
+            }
+        }
+
+        impl<W: ByteWriter + ?Sized> HyperWrite<W> for $name {
+            #[inline(always)]
+            fn hyper_write(&self, writer: &mut W) -> Result<(), ByteError> {
+                writer.write_wire(self);
+                Ok(())
+            }
+        }
+
+        impl<E, L: LengthPrefix> HyperEncode<E, L> for $name {
+            #[inline(always)]
+            fn encode<W: EndianWriter<E> + ?Sized>(&self, writer: &mut W) -> Result<(), ByteError> {
+                writer.write_wire(self);
+                Ok(())
+            }
+        }
+
+        impl<E, L: LengthPrefix> HyperDecode<E, L> for $name {
+            #[inline(always)]
+            fn decode<R: EndianReader<E> + ?Sized>(reader: &mut R) -> Result<Self, ByteError> {
+                Ok(reader.read_wire())
+            }
+        }
+    };
+}
+
+wire_number!(integer U16Be, "A" u16, 2, read_u16_be, to_be_bytes, "big-endian");
+wire_number!(integer U16Le, "A" u16, 2, read_u16_le, to_le_bytes, "little-endian");
+wire_number!(integer U32Be, "A" u32, 4, read_u32_be, to_be_bytes, "big-endian");
+wire_number!(integer U32Le, "A" u32, 4, read_u32_le, to_le_bytes, "little-endian");
+wire_number!(integer U64Be, "A" u64, 8, read_u64_be, to_be_bytes, "big-endian");
+wire_number!(integer U64Le, "A" u64, 8, read_u64_le, to_le_bytes, "little-endian");
+wire_number!(integer U128Be, "A" u128, 16, read_u128_be, to_be_bytes, "big-endian");
+wire_number!(integer U128Le, "A" u128, 16, read_u128_le, to_le_bytes, "little-endian");
+wire_number!(integer I16Be, "An" i16, 2, read_i16_be, to_be_bytes, "big-endian");
+wire_number!(integer I16Le, "An" i16, 2, read_i16_le, to_le_bytes, "little-endian");
+wire_number!(integer I32Be, "An" i32, 4, read_i32_be, to_be_bytes, "big-endian");
+wire_number!(integer I32Le, "An" i32, 4, read_i32_le, to_le_bytes, "little-endian");
+wire_number!(integer I64Be, "An" i64, 8, read_i64_be, to_be_bytes, "big-endian");
+wire_number!(integer I64Le, "An" i64, 8, read_i64_le, to_le_bytes, "little-endian");
+wire_number!(integer I128Be, "An" i128, 16, read_i128_be, to_be_bytes, "big-endian");
+wire_number!(integer I128Le, "An" i128, 16, read_i128_le, to_le_bytes, "little-endian");
+wire_number!(float F32Be, "A" f32, 4, read_f32_be, to_be_bytes, "big-endian");
+wire_number!(float F32Le, "A" f32, 4, read_f32_le, to_le_bytes, "little-endian");
+wire_number!(float F64Be, "A" f64, 8, read_f64_be, to_be_bytes, "big-endian");
+wire_number!(float F64Le, "A" f64, 8, read_f64_le, to_le_bytes, "little-endian");
@@ -377,3 +377,89 @@ impl NativeWriter {
         Self { byte_array: buffer }
     }
 }
+
+/// A writer which keeps none of the bytes written to it, only counting them, so the size of a message can be
+/// known before writing it for real. It implements all three endian writer traits, so any function writing
+/// through them can be run in "measure" mode first.<br/>
+/// As nothing is kept, [`ByteWriter::to_vec`] and [`ByteWriter::as_slice`] are always empty, and
+/// [`ByteWriter::as_mut_vec`] panics, as every write of the writer traits goes through
+/// [`ByteWriter::put_bytes`] or [`ByteWriter::put_zeros`] instead.
+///
+/// # Examples
+/// ```
+/// use hyper_byte::writer::{FastByteWriter, SizeCounter};
+/// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+///
+/// fn write_message(writer: &mut impl BigEndianByteWriter) {
+///     writer.write_u16_be(7);
+///     writer.write_bytes_be(b"hyper");
+///     writer.write_f64_be(1.5);
+/// }
+///
+/// let mut counter = SizeCounter::new();
+/// write_message(&mut counter);
+/// assert_eq!(counter.len(), 15);
+///
+/// let mut writer = FastByteWriter::from(Vec::with_capacity(counter.len()));
+/// write_message(&mut writer);
+/// assert_eq!(writer.len(), writer.capacity());
+/// ```
+#[derive(Default)]
+pub struct SizeCounter {
+    count: usize,
+}
+
+impl ByteWriter for SizeCounter {
+    fn to_vec(self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &[]
+    }
+
+    /// Panics, as there are no bytes to hand out
+    fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+        panic!("Attempted to write to the bytes of a size counter, which keeps none.");
+    }
+
+    #[inline(always)]
+    fn put_bytes(&mut self, bytes: &[u8]) {
+        self.count += bytes.len();
+    }
+
+    #[inline(always)]
+    fn put_zeros(&mut self, amount: usize) {
+        self.count += amount;
+    }
+}
+
+impl BigEndianByteWriter for SizeCounter {}
+impl LittleEndianByteWriter for SizeCounter {}
+impl NativeEndianByteWriter for SizeCounter {}
+
+impl SizeCounter {
+    /// A counter starting from zero bytes
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    /// The amount of bytes written so far
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Whether nothing has been written so far
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Starts counting from zero again
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
@@ -1,6 +1,26 @@
 use alloc::vec::Vec;
+use core::mem::size_of;
+use core::slice;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime};
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+use crate::codec::HyperEncode;
+use crate::endian::{BigEndian, EndianWriter, LittleEndian, NativeEndian};
+use crate::error::ByteError;
+use crate::view::View;
+use crate::fixed::{quantize, FixedMode};
+#[cfg(feature = "std")]
+use crate::time;
+use crate::search::find_byte;
 
 pub trait ByteWriter {
     /// Consumes the writer to return the bytes inside<br/>
@@ -68,6 +88,351 @@ pub trait ByteWriter {
     /// assert_eq!(reader.read_u16_be(), my_simple_tuple.1);
     /// ```
     fn as_mut_vec(&mut self) -> &mut Vec<u8>;
+
+    /// For writing raw bytes to the end of the writer, which every write of a value goes through<br/>
+    /// Writers which do not keep their bytes, such as [`SizeCounter`](crate::writer::SizeCounter), or which
+    /// are bounded, such as [`RingStream`](crate::hyper_stream::RingStream), replace it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.put_bytes(b"hyper");
+    /// writer.put_bytes(&[0, 1]);
+    ///
+    /// assert_eq!(writer.to_vec(), b"hyper\x00\x01");
+    /// ```
+    #[inline(always)]
+    fn put_bytes(&mut self, bytes: &[u8]) {
+        self.as_mut_vec().extend_from_slice(bytes)
+    }
+
+    /// For writing `amount` zeroes to the end of the writer, such as padding, without a slice of them<br/>
+    /// Writers which replace [`ByteWriter::put_bytes`] replace this as well
+    /// # Examples
+    /// ```
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.put_bytes(&[1]);
+    /// writer.put_zeros(3);
+    ///
+    /// assert_eq!(writer.to_vec(), [1, 0, 0, 0]);
+    /// ```
+    #[inline(always)]
+    fn put_zeros(&mut self, amount: usize) {
+        let bytes = self.as_mut_vec();
+        bytes.resize(bytes.len() + amount, 0);
+    }
+
+    /// For writing a string followed by a NUL terminator<br/>
+    /// Returns an error if the string itself contains a NUL, as it could not be read back
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_cstr("hyper").unwrap();
+    /// assert!(writer.write_cstr("by\0te").is_err());
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_cstr().unwrap().to_str(), Ok("hyper"));
+    /// ```
+    #[inline(always)]
+    fn write_cstr(&mut self, value: &str) -> Result<(), ByteError> {
+        if let Some(position) = find_byte(value.as_bytes(), 0) {
+            return Err(ByteError::InteriorNul { position });
+        }
+        self.put_bytes(value.as_bytes());
+        self.put_bytes(&[0]);
+        Ok(())
+    }
+
+    /// For writing a `bool` as a single `0` or `1` byte<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (true, false);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bool(my_simple_tuple.0);
+    /// writer.write_bool(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bool().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bool().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bool(&mut self, value: bool) {
+        self.put_bytes(&[value as u8])
+    }
+
+    /// For writing an `Option` as a one byte presence tag, followed by the value written with `write` if there is one<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::{BigEndianByteReader, ByteReader};
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (Some(193u32), None);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_option(&my_simple_tuple.0, |writer, &value| writer.write_u32_be(value));
+    /// writer.write_option(&my_simple_tuple.1, |writer, &value| writer.write_u32_be(value));
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_option(|reader| Ok(reader.read_u32_be())).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_option(|reader| Ok(reader.read_u32_be())).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_option<T>(&mut self, value: &Option<T>, write: impl FnOnce(&mut Self, &T)) {
+        match value {
+            None => self.put_bytes(&[0]),
+            Some(value) => {
+                self.put_bytes(&[1]);
+                write(self, value);
+            }
+        }
+    }
+
+    /// For writing a `Result` as a one byte tag, `0` being followed by the value written with `write_ok` and `1` by the value written with `write_err`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::{BigEndianByteReader, ByteReader};
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple: (Result<u32, u8>, Result<u32, u8>) = (Ok(193), Err(22));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// for value in [&my_simple_tuple.0, &my_simple_tuple.1] {
+    ///     writer.write_result(
+    ///         value,
+    ///         |writer, &value| writer.write_u32_be(value),
+    ///         |writer, &error| writer.write_u8_be(error),
+    ///     );
+    /// }
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// for value in [my_simple_tuple.0, my_simple_tuple.1] {
+    ///     let read = reader.read_result(|reader| Ok(reader.read_u32_be()), |reader| Ok(reader.read_u8_be()));
+    ///     assert_eq!(read.unwrap(), value);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn write_result<T, E>(
+        &mut self,
+        value: &Result<T, E>,
+        write_ok: impl FnOnce(&mut Self, &T),
+        write_err: impl FnOnce(&mut Self, &E),
+    ) {
+        match value {
+            Ok(value) => {
+                self.put_bytes(&[0]);
+                write_ok(self, value);
+            }
+            Err(error) => {
+                self.put_bytes(&[1]);
+                write_err(self, error);
+            }
+        }
+    }
+
+    /// For writing a `f32` from `0.0` to `1.0` as an unsigned normalised 8-bit integer (UNORM8)<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, 0.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unorm8(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_unorm8(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unorm8(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unorm8(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unorm8(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, u8::MAX as f64, 0, u8::MAX as i64, mode)?;
+        self.put_bytes(&[fixed as u8]);
+        Ok(())
+    }
+
+    /// For writing a `f32` from `-1.0` to `1.0` as a signed normalised 8-bit integer (SNORM8)<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, -1.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_snorm8(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_snorm8(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_snorm8(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_snorm8(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_snorm8(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, i8::MAX as f64, -(i8::MAX as i64), i8::MAX as i64, mode)?;
+        self.put_bytes(&[fixed as i8 as u8]);
+        Ok(())
+    }
+
+    /// For writing a `u64` as an unsigned LEB128 variable-length integer, 7 bits at a time with the top bit set while more follow<br/>
+    /// Values below `128` take a single byte, and `u64::MAX` takes 10
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (300u64, u64::MAX);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_varint_u64(my_simple_tuple.0);
+    /// writer.write_varint_u64(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_varint_u64().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_varint_u64().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_varint_u64(&mut self, value: u64) {
+        let mut bytes = [0; 10];
+        let mut length = 0;
+        let mut value = value;
+        while value > 0x7F {
+            bytes[length] = value as u8 | 0x80;
+            length += 1;
+            value >>= 7;
+        }
+        bytes[length] = value as u8;
+        self.put_bytes(&bytes[..=length]);
+    }
+
+    /// For writing an `i64` as a signed LEB128 variable-length integer with zigzag encoding, so small negative values stay small<br/>
+    /// Values from `-64` to `63` take a single byte
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (-1i64, i64::MIN);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_varint_i64(my_simple_tuple.0);
+    /// writer.write_varint_i64(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_varint_i64().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_varint_i64().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_varint_i64(&mut self, value: i64) {
+        self.write_varint_u64(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    /// For writing a [`View`], such as the types of [`crate::wire`], by copying its bytes as they are<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::ByteReader;
+    /// use hyper_byte::wire::{U16Le, U32Be};
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::ByteWriter;
+    ///
+    /// let my_simple_tuple = (U32Be::new(193), U16Le::new(22));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_wire(&my_simple_tuple.0);
+    /// writer.write_wire(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// assert_eq!(buffer, [0, 0, 0, 193, 22, 0]);
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_wire::<U32Be>(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_wire::<U16Le>(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_wire<T: View>(&mut self, value: &T) {
+        // SAFETY: A View has no padding, so every one of its bytes is initialised
+        let bytes = unsafe { slice::from_raw_parts((value as *const T).cast::<u8>(), size_of::<T>()) };
+        self.put_bytes(bytes)
+    }
+}
+
+/// Whether `value` fits in `nbytes` bytes as an unsigned integer
+#[inline(always)]
+fn fits_unsigned(value: u128, nbytes: usize) -> bool {
+    nbytes >= 16 || value >> (8 * nbytes) == 0
+}
+
+/// Whether `value` fits in `nbytes` bytes as a signed integer
+#[inline(always)]
+fn fits_signed(value: i128, nbytes: usize) -> bool {
+    match nbytes {
+        0 => value == 0,
+        16.. => true,
+        _ => {
+            let shift = 128 - 8 * nbytes as u32;
+            (value << shift) >> shift == value
+        }
+    }
+}
+
+/// Writes the lowest `nbytes` bytes of `value`, in big-endian order if `big_endian` is set
+#[inline(always)]
+fn write_uint_bytes<W: ByteWriter + ?Sized>(writer: &mut W, value: u128, nbytes: usize, big_endian: bool) {
+    if big_endian {
+        writer.put_bytes(&value.to_be_bytes()[16 - nbytes..]);
+    } else {
+        writer.put_bytes(&value.to_le_bytes()[..nbytes]);
+    }
 }
 
 pub trait NativeEndianByteWriter: ByteWriter {
@@ -94,7 +459,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_u8_ne(&mut self, value: u8) {
-        self.as_mut_vec().push(value)
+        self.put_bytes(&[value])
     }
 
     /// For writing a `u16` in native-endian order<br/>
@@ -119,7 +484,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_u16_ne(&mut self, value: u16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `u32` in native-endian order<br/>
@@ -144,7 +509,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_u32_ne(&mut self, value: u32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `u64` in native-endian order<br/>
@@ -169,7 +534,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_u64_ne(&mut self, value: u64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `u128` in native-endian order<br/>
@@ -194,7 +559,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_u128_ne(&mut self, value: u128) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `usize` in native-endian order<br/>
@@ -219,7 +584,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_usize_ne(&mut self, value: usize) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing an `i8` in native-endian order<br/>
@@ -244,7 +609,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_i8_ne(&mut self, value: i8) {
-        self.as_mut_vec().push(value as u8)
+        self.put_bytes(&[value as u8])
     }
 
     /// For writing a `i16` in native-endian order<br/>
@@ -269,7 +634,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_i16_ne(&mut self, value: i16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `i32` in native-endian order<br/>
@@ -294,7 +659,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_i32_ne(&mut self, value: i32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `i64` in native-endian order<br/>
@@ -319,7 +684,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_i64_ne(&mut self, value: i64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `i128` in native-endian order<br/>
@@ -344,7 +709,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_i128_ne(&mut self, value: i128) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     /// For writing a `isize` in native-endian order<br/>
@@ -369,7 +734,7 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_isize_ne(&mut self, value: isize) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
     #[cfg(feature = "half")]
@@ -396,35 +761,38 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// ```
     #[inline(always)]
     fn write_f16_ne(&mut self, value: f16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+        self.put_bytes(&value.to_ne_bytes())
     }
 
-    /// For writing a `f32` in native-endian order<br/>
+    #[cfg(feature = "half")]
+    /// For writing a `bf16` in native-endian order<br/>
     /// # Examples
     /// ```
+    /// use half::bf16;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193.32f32, 22.13f32);
+    /// let my_simple_tuple = (bf16::from_f32_const(133.0f32), bf16::from_f32_const(13.0f32));
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_f32_ne(my_simple_tuple.0);
-    /// writer.write_f32_ne(my_simple_tuple.1);
+    /// writer.write_bf16_ne(my_simple_tuple.0);
+    /// writer.write_bf16_ne(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f32_ne(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f32_ne(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_bf16_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_ne(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f32_ne(&mut self, value: f32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_bf16_ne(&mut self, value: bf16) {
+        self.put_bytes(&value.to_ne_bytes())
     }
 
-    /// For writing a `f64` in native-endian order<br/>
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `f16` in native-endian order, rounding to the nearest representable value<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -432,24 +800,25 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64, 22.3f64);
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_f64_ne(my_simple_tuple.0);
-    /// writer.write_f64_ne(my_simple_tuple.1);
+    /// writer.write_f32_as_f16_ne(my_simple_tuple.0);
+    /// writer.write_f32_as_f16_ne(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f64_ne(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f64_ne(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f16_as_f32_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_as_f32_ne(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f64_ne(&mut self, value: f64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_f32_as_f16_ne(&mut self, value: f32) {
+        self.write_f16_ne(f16::from_f32(value))
     }
 
-    /// For writing a set of native-endian bytes into native-endian order<br/>
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `bf16` in native-endian order, rounding to the nearest representable value<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -457,557 +826,3410 @@ pub trait NativeEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_bytes_ne(&my_simple_tuple.0);
-    /// writer.write_bytes_ne(&my_simple_tuple.1);
+    /// writer.write_f32_as_bf16_ne(my_simple_tuple.0);
+    /// writer.write_f32_as_bf16_ne(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_n_ne(my_simple_tuple.0.len()), my_simple_tuple.0);
-    /// assert_eq!(reader.read_n_ne(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// assert_eq!(reader.read_bf16_as_f32_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_as_f32_ne(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_bytes_ne(&mut self, value: &[u8]) {
-        self.as_mut_vec().extend(value)
+    fn write_f32_as_bf16_ne(&mut self, value: f32) {
+        self.write_bf16_ne(bf16::from_f32(value))
     }
-}
 
-pub trait LittleEndianByteWriter: ByteWriter {
-    /// For writing a byte in little-endian order<br/>
-    /// It is not recommended to use this unless the value in particular is quite literally one byte
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `f16`s in native-endian order, rounding each to the nearest representable value<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193u8, 22u8);
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u8_le(my_simple_tuple.0);
-    /// writer.write_u8_le(my_simple_tuple.1);
+    /// writer.write_f32_slice_as_f16_ne(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_f16_ne(&my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u8_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u8_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f16_slice_as_f32_ne(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_slice_as_f32_ne(2), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u8_le(&mut self, value: u8) {
-        self.as_mut_vec().push(value.to_le())
+    fn write_f32_slice_as_f16_ne(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_f16_ne(value));
     }
 
-    /// For writing a `u16` in little-endian order<br/>
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `bf16`s in native-endian order, rounding each to the nearest representable value<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193u16, 22u16);
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u16_le(my_simple_tuple.0);
-    /// writer.write_u16_le(my_simple_tuple.1);
+    /// writer.write_f32_slice_as_bf16_ne(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_bf16_ne(&my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u16_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u16_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_bf16_slice_as_f32_ne(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_slice_as_f32_ne(2), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u16_le(&mut self, value: u16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_f32_slice_as_bf16_ne(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_bf16_ne(value));
     }
 
-    /// For writing a `u32` in little-endian order<br/>
+    /// For writing a `f32` in native-endian order<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193u32, 22u32);
+    /// let my_simple_tuple = (193.32f32, 22.13f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u32_le(my_simple_tuple.0);
-    /// writer.write_u32_le(my_simple_tuple.1);
+    /// writer.write_f32_ne(my_simple_tuple.0);
+    /// writer.write_f32_ne(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u32_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u32_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f32_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f32_ne(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u32_le(&mut self, value: u32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_f32_ne(&mut self, value: f32) {
+        self.put_bytes(&value.to_ne_bytes())
     }
 
-    /// For writing a `u64` in little-endian order<br/>
+    /// For writing a `f64` in native-endian order<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193u64, 22u64);
+    /// let my_simple_tuple = (193.6f64, 22.3f64);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u64_le(my_simple_tuple.0);
-    /// writer.write_u64_le(my_simple_tuple.1);
+    /// writer.write_f64_ne(my_simple_tuple.0);
+    /// writer.write_f64_ne(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u64_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u64_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f64_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f64_ne(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u64_le(&mut self, value: u64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_f64_ne(&mut self, value: f64) {
+        self.put_bytes(&value.to_ne_bytes())
     }
 
-    /// For writing a `u128` in little-endian order<br/>
+    /// For writing a set of native-endian bytes into native-endian order<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bytes_ne(&my_simple_tuple.0);
+    /// writer.write_bytes_ne(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_n_ne(my_simple_tuple.0.len()), my_simple_tuple.0);
+    /// assert_eq!(reader.read_n_ne(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bytes_ne(&mut self, value: &[u8]) {
+        self.put_bytes(value)
+    }
+
+    /// For writing a `char` as its 4 byte scalar value in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ('h', '🦀');
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_char_ne(my_simple_tuple.0);
+    /// writer.write_char_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_char_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_char_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_char_ne(&mut self, value: char) {
+        self.write_u32_ne(value as u32)
+    }
+
+    /// For writing a `NonZeroU8` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU8::new(93u8).unwrap(), NonZeroU8::new(22u8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u8_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_u8_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u8_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u8_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u8_ne(&mut self, value: NonZeroU8) {
+        self.write_u8_ne(value.get())
+    }
+
+    /// For writing a `NonZeroU16` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU16::new(93u16).unwrap(), NonZeroU16::new(22u16).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u16_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_u16_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u16_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u16_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u16_ne(&mut self, value: NonZeroU16) {
+        self.write_u16_ne(value.get())
+    }
+
+    /// For writing a `NonZeroU32` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU32;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU32::new(93u32).unwrap(), NonZeroU32::new(22u32).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u32_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_u32_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u32_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u32_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u32_ne(&mut self, value: NonZeroU32) {
+        self.write_u32_ne(value.get())
+    }
+
+    /// For writing a `NonZeroU64` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU64;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU64::new(93u64).unwrap(), NonZeroU64::new(22u64).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u64_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_u64_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u64_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u64_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u64_ne(&mut self, value: NonZeroU64) {
+        self.write_u64_ne(value.get())
+    }
+
+    /// For writing a `NonZeroU128` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU128;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU128::new(93u128).unwrap(), NonZeroU128::new(22u128).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u128_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_u128_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u128_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u128_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u128_ne(&mut self, value: NonZeroU128) {
+        self.write_u128_ne(value.get())
+    }
+
+    /// For writing a `NonZeroUsize` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroUsize::new(93usize).unwrap(), NonZeroUsize::new(22usize).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_usize_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_usize_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_usize_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_usize_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_usize_ne(&mut self, value: NonZeroUsize) {
+        self.write_usize_ne(value.get())
+    }
+
+    /// For writing a `NonZeroI8` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI8::new(93i8).unwrap(), NonZeroI8::new(22i8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i8_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_i8_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i8_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i8_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i8_ne(&mut self, value: NonZeroI8) {
+        self.write_i8_ne(value.get())
+    }
+
+    /// For writing a `NonZeroI16` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI16::new(93i16).unwrap(), NonZeroI16::new(22i16).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i16_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_i16_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i16_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i16_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i16_ne(&mut self, value: NonZeroI16) {
+        self.write_i16_ne(value.get())
+    }
+
+    /// For writing a `NonZeroI32` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI32;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI32::new(93i32).unwrap(), NonZeroI32::new(22i32).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i32_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_i32_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i32_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i32_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i32_ne(&mut self, value: NonZeroI32) {
+        self.write_i32_ne(value.get())
+    }
+
+    /// For writing a `NonZeroI64` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI64;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI64::new(93i64).unwrap(), NonZeroI64::new(22i64).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i64_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_i64_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i64_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i64_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i64_ne(&mut self, value: NonZeroI64) {
+        self.write_i64_ne(value.get())
+    }
+
+    /// For writing a `NonZeroI128` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI128;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI128::new(93i128).unwrap(), NonZeroI128::new(22i128).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i128_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_i128_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i128_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i128_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i128_ne(&mut self, value: NonZeroI128) {
+        self.write_i128_ne(value.get())
+    }
+
+    /// For writing a `NonZeroIsize` in native-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroIsize;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroIsize::new(93isize).unwrap(), NonZeroIsize::new(22isize).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_isize_ne(my_simple_tuple.0);
+    /// writer.write_nonzero_isize_ne(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_isize_ne().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_isize_ne().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_isize_ne(&mut self, value: NonZeroIsize) {
+        self.write_isize_ne(value.get())
+    }
+
+    /// For writing a `f64` as a signed Q16.16 fixed-point number in native-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.5f64, -2.25f64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_q16_16_ne(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_q16_16_ne(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_q16_16_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_q16_16_ne(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_q16_16_ne(&mut self, value: f64, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value, 65536.0, i32::MIN as i64, i32::MAX as i64, mode)?;
+        self.write_i32_ne(fixed as i32);
+        Ok(())
+    }
+
+    /// For writing a `f32` as a signed 2.14 fixed-point number (F2DOT14) in native-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.5f32, -0.25f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f2dot14_ne(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_f2dot14_ne(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f2dot14_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f2dot14_ne(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f2dot14_ne(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, 16384.0, i16::MIN as i64, i16::MAX as i64, mode)?;
+        self.write_i16_ne(fixed as i16);
+        Ok(())
+    }
+
+    /// For writing a `f32` from `0.0` to `1.0` as an unsigned normalised 16-bit integer (UNORM16) in native-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, 0.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unorm16_ne(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_unorm16_ne(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unorm16_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unorm16_ne(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unorm16_ne(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, u16::MAX as f64, 0, u16::MAX as i64, mode)?;
+        self.write_u16_ne(fixed as u16);
+        Ok(())
+    }
+
+    /// For writing a `f32` from `-1.0` to `1.0` as a signed normalised 16-bit integer (SNORM16) in native-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, -1.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_snorm16_ne(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_snorm16_ne(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_snorm16_ne(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_snorm16_ne(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_snorm16_ne(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, i16::MAX as f64, -(i16::MAX as i64), i16::MAX as i64, mode)?;
+        self.write_i16_ne(fixed as i16);
+        Ok(())
+    }
+
+    /// For writing an unsigned `u64` in [`nbytes`] native-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (0x010203u64, 42u64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_uint_ne(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_uint_ne(my_simple_tuple.1, 3).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_uint_ne(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint_ne(3).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_uint_ne(&mut self, value: u64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_unsigned(value as u128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, cfg!(target_endian = "big"));
+        Ok(())
+    }
+
+    /// For writing a signed `i64` in [`nbytes`] native-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (-2i64, 0x7FFFFFi64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_int_ne(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_int_ne(my_simple_tuple.1, 3).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_int_ne(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int_ne(3).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_int_ne(&mut self, value: i64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_signed(value as i128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as i128 as u128, nbytes, cfg!(target_endian = "big"));
+        Ok(())
+    }
+
+    /// For writing an unsigned `u128` in [`nbytes`] native-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1u128 << 100, 7u128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_uint128_ne(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_uint128_ne(my_simple_tuple.1, 13).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_uint128_ne(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint128_ne(13).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_uint128_ne(&mut self, value: u128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_unsigned(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value, nbytes, cfg!(target_endian = "big"));
+        Ok(())
+    }
+
+    /// For writing a signed `i128` in [`nbytes`] native-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, NativeEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (-(1i128 << 100), 7i128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_int128_ne(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_int128_ne(my_simple_tuple.1, 13).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_int128_ne(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int128_ne(13).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_int128_ne(&mut self, value: i128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_signed(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, cfg!(target_endian = "big"));
+        Ok(())
+    }
+
+    /// For encoding a [`HyperEncode`] value in native-endian, with `u32` length prefixes<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::NativeEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{NativeEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (7u32, vec![1.5f32, -2.0]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.encode_ne(&my_simple_tuple).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.decode_ne::<(u32, Vec<f32>)>().unwrap(), my_simple_tuple);
+    /// ```
+    #[inline(always)]
+    fn encode_ne<T: HyperEncode<NativeEndian> + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        EndianWriter::<NativeEndian>::encode(self, value)
+    }
+}
+
+pub trait LittleEndianByteWriter: ByteWriter {
+    /// For writing a byte in little-endian order<br/>
+    /// It is not recommended to use this unless the value in particular is quite literally one byte
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193u8, 22u8);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u8_le(my_simple_tuple.0);
+    /// writer.write_u8_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u8_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u8_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u8_le(&mut self, value: u8) {
+        self.put_bytes(&[value.to_le()])
+    }
+
+    /// For writing a `u16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193u16, 22u16);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u16_le(my_simple_tuple.0);
+    /// writer.write_u16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u16_le(&mut self, value: u16) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `u32` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193u32, 22u32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u32_le(my_simple_tuple.0);
+    /// writer.write_u32_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u32_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u32_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u32_le(&mut self, value: u32) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `u64` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193u64, 22u64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u64_le(my_simple_tuple.0);
+    /// writer.write_u64_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u64_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u64_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u64_le(&mut self, value: u64) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `u128` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193u128, 22u128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u128_le(my_simple_tuple.0);
+    /// writer.write_u128_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u128_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u128_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u128_le(&mut self, value: u128) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `usize` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193usize, 22usize);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_usize_le(my_simple_tuple.0);
+    /// writer.write_usize_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_usize_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_usize_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_usize_le(&mut self, value: usize) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing an `i8` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (19i8, 22i8);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i8_le(my_simple_tuple.0);
+    /// writer.write_i8_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i8_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i8_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i8_le(&mut self, value: i8) {
+        self.put_bytes(&[(value as u8).to_le()])
+    }
+
+    /// For writing a `i16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193i16, 22i16);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i16_le(my_simple_tuple.0);
+    /// writer.write_i16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i16_le(&mut self, value: i16) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `i32` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193i32, 22i32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i32_le(my_simple_tuple.0);
+    /// writer.write_i32_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i32_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i32_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i32_le(&mut self, value: i32) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `i64` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193i64, 22i64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i64_le(my_simple_tuple.0);
+    /// writer.write_i64_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i64_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i64_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i64_le(&mut self, value: i64) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `i128` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193i128, 22i128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i128_le(my_simple_tuple.0);
+    /// writer.write_i128_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i128_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i128_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i128_le(&mut self, value: i128) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `isize` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193isize, 22isize);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_isize_le(my_simple_tuple.0);
+    /// writer.write_isize_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_isize_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_isize_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_isize_le(&mut self, value: isize) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use half::f16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (f16::from_f32_const(133.0f32), f16::from_f32_const(13.0f32));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f16_le(my_simple_tuple.0);
+    /// writer.write_f16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f16_le(&mut self, value: f16) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `bf16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use half::bf16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (bf16::from_f32_const(133.0f32), bf16::from_f32_const(13.0f32));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bf16_le(my_simple_tuple.0);
+    /// writer.write_bf16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bf16_le(&mut self, value: bf16) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `f16` in little-endian order, rounding to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_as_f16_le(my_simple_tuple.0);
+    /// writer.write_f32_as_f16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f16_as_f32_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_as_f32_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_as_f16_le(&mut self, value: f32) {
+        self.write_f16_le(f16::from_f32(value))
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `bf16` in little-endian order, rounding to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_as_bf16_le(my_simple_tuple.0);
+    /// writer.write_f32_as_bf16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_as_f32_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_as_f32_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_as_bf16_le(&mut self, value: f32) {
+        self.write_bf16_le(bf16::from_f32(value))
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `f16`s in little-endian order, rounding each to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_slice_as_f16_le(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_f16_le(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f16_slice_as_f32_le(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_slice_as_f32_le(2), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_slice_as_f16_le(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_f16_le(value));
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `bf16`s in little-endian order, rounding each to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_slice_as_bf16_le(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_bf16_le(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_slice_as_f32_le(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_slice_as_f32_le(2), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_slice_as_bf16_le(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_bf16_le(value));
+    }
+
+    /// For writing a `f32` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193.32f32, 22.13f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_le(my_simple_tuple.0);
+    /// writer.write_f32_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f32_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f32_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_le(&mut self, value: f32) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a `f64` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193.6f64, 22.3f64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f64_le(my_simple_tuple.0);
+    /// writer.write_f64_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f64_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f64_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f64_le(&mut self, value: f64) {
+        self.put_bytes(&value.to_le_bytes())
+    }
+
+    /// For writing a set of native-endian bytes into little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bytes_le(&my_simple_tuple.0);
+    /// writer.write_bytes_le(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_n_le(my_simple_tuple.0.len()), my_simple_tuple.0);
+    /// assert_eq!(reader.read_n_le(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bytes_le(&mut self, value: &[u8]) {
+        self.put_bytes(value)
+    }
+
+    /// For writing a string as UTF-16 code units in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_utf16_le(my_simple_tuple.0);
+    /// writer.write_utf16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_utf16_le(5).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_utf16_le(7).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_utf16_le(&mut self, value: &str) {
+        value.encode_utf16().for_each(|unit| self.write_u16_le(unit));
+    }
+
+    /// For writing a string as UTF-16 code units in little-endian order, prefixed by the amount of code units as a `u16`<br/>
+    /// Returns an error if the string needs more than [`u16::MAX`] code units
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_utf16_le_prefixed(my_simple_tuple.0).unwrap();
+    /// writer.write_utf16_le_prefixed(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_utf16_le_prefixed().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_utf16_le_prefixed().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_utf16_le_prefixed(&mut self, value: &str) -> Result<(), ByteError> {
+        let len_units = value.encode_utf16().count();
+        let prefix = u16::try_from(len_units).map_err(|_| ByteError::TooLong {
+            length: len_units,
+            maximum: u16::MAX as usize,
+        })?;
+        self.write_u16_le(prefix);
+        self.write_utf16_le(value);
+        Ok(())
+    }
+
+    /// For writing a `char` as its 4 byte scalar value in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ('h', '🦀');
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_char_le(my_simple_tuple.0);
+    /// writer.write_char_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_char_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_char_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_char_le(&mut self, value: char) {
+        self.write_u32_le(value as u32)
+    }
+
+    /// For writing a `NonZeroU8` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU8::new(93u8).unwrap(), NonZeroU8::new(22u8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u8_le(my_simple_tuple.0);
+    /// writer.write_nonzero_u8_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u8_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u8_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u8_le(&mut self, value: NonZeroU8) {
+        self.write_u8_le(value.get())
+    }
+
+    /// For writing a `NonZeroU16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU16::new(93u16).unwrap(), NonZeroU16::new(22u16).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u16_le(my_simple_tuple.0);
+    /// writer.write_nonzero_u16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u16_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u16_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u16_le(&mut self, value: NonZeroU16) {
+        self.write_u16_le(value.get())
+    }
+
+    /// For writing a `NonZeroU32` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU32;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU32::new(93u32).unwrap(), NonZeroU32::new(22u32).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u32_le(my_simple_tuple.0);
+    /// writer.write_nonzero_u32_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u32_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u32_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u32_le(&mut self, value: NonZeroU32) {
+        self.write_u32_le(value.get())
+    }
+
+    /// For writing a `NonZeroU64` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU64;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU64::new(93u64).unwrap(), NonZeroU64::new(22u64).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u64_le(my_simple_tuple.0);
+    /// writer.write_nonzero_u64_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u64_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u64_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u64_le(&mut self, value: NonZeroU64) {
+        self.write_u64_le(value.get())
+    }
+
+    /// For writing a `NonZeroU128` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU128;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU128::new(93u128).unwrap(), NonZeroU128::new(22u128).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u128_le(my_simple_tuple.0);
+    /// writer.write_nonzero_u128_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u128_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u128_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u128_le(&mut self, value: NonZeroU128) {
+        self.write_u128_le(value.get())
+    }
+
+    /// For writing a `NonZeroUsize` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroUsize::new(93usize).unwrap(), NonZeroUsize::new(22usize).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_usize_le(my_simple_tuple.0);
+    /// writer.write_nonzero_usize_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_usize_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_usize_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_usize_le(&mut self, value: NonZeroUsize) {
+        self.write_usize_le(value.get())
+    }
+
+    /// For writing a `NonZeroI8` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI8::new(93i8).unwrap(), NonZeroI8::new(22i8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i8_le(my_simple_tuple.0);
+    /// writer.write_nonzero_i8_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i8_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i8_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i8_le(&mut self, value: NonZeroI8) {
+        self.write_i8_le(value.get())
+    }
+
+    /// For writing a `NonZeroI16` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI16::new(93i16).unwrap(), NonZeroI16::new(22i16).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i16_le(my_simple_tuple.0);
+    /// writer.write_nonzero_i16_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i16_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i16_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i16_le(&mut self, value: NonZeroI16) {
+        self.write_i16_le(value.get())
+    }
+
+    /// For writing a `NonZeroI32` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI32;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI32::new(93i32).unwrap(), NonZeroI32::new(22i32).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i32_le(my_simple_tuple.0);
+    /// writer.write_nonzero_i32_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i32_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i32_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i32_le(&mut self, value: NonZeroI32) {
+        self.write_i32_le(value.get())
+    }
+
+    /// For writing a `NonZeroI64` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI64;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI64::new(93i64).unwrap(), NonZeroI64::new(22i64).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i64_le(my_simple_tuple.0);
+    /// writer.write_nonzero_i64_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i64_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i64_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i64_le(&mut self, value: NonZeroI64) {
+        self.write_i64_le(value.get())
+    }
+
+    /// For writing a `NonZeroI128` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI128;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI128::new(93i128).unwrap(), NonZeroI128::new(22i128).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i128_le(my_simple_tuple.0);
+    /// writer.write_nonzero_i128_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i128_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i128_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_i128_le(&mut self, value: NonZeroI128) {
+        self.write_i128_le(value.get())
+    }
+
+    /// For writing a `NonZeroIsize` in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroIsize;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroIsize::new(93isize).unwrap(), NonZeroIsize::new(22isize).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_isize_le(my_simple_tuple.0);
+    /// writer.write_nonzero_isize_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_isize_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_isize_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_isize_le(&mut self, value: NonZeroIsize) {
+        self.write_isize_le(value.get())
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u32` of whole seconds since the Unix epoch in little-endian order<br/>
+    /// Times before the epoch or after 2106 result in an error
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_secs(1_700_000_000), UNIX_EPOCH);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unix_secs_u32_le(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_u32_le(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unix_secs_u32_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_u32_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unix_secs_u32_le(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        let seconds = time::to_unix(value)?.as_secs();
+        self.write_u32_le(u32::try_from(seconds).map_err(|_| ByteError::OutOfRange)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of whole seconds since the Unix epoch in little-endian order<br/>
+    /// Times before the epoch result in an error
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_secs(1_700_000_000), UNIX_EPOCH);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unix_secs_u64_le(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_u64_le(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unix_secs_u64_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_u64_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unix_secs_u64_le(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_u64_le(time::to_unix(value)?.as_secs());
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of seconds since the Unix epoch followed by a `u32` of nanoseconds in little-endian order<br/>
+    /// Times before the epoch result in an error
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789), UNIX_EPOCH);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unix_secs_nanos_le(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_nanos_le(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unix_secs_nanos_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_nanos_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unix_secs_nanos_le(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_duration_le(time::to_unix(value)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of whole milliseconds since the Unix epoch in little-endian order<br/>
+    /// Times before the epoch result in an error
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_millis(1_700_000_000_123), UNIX_EPOCH);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unix_millis_le(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_millis_le(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unix_millis_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_millis_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unix_millis_le(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        let millis = time::to_unix(value)?.as_millis();
+        self.write_u64_le(u64::try_from(millis).map_err(|_| ByteError::OutOfRange)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `Duration` as a `u64` of seconds followed by a `u32` of nanoseconds in little-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (Duration::new(193, 22), Duration::MAX);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_duration_le(my_simple_tuple.0);
+    /// writer.write_duration_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_duration_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_duration_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_duration_le(&mut self, value: Duration) {
+        self.write_u64_le(value.as_secs());
+        self.write_u32_le(value.subsec_nanos());
+    }
+
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a Windows FILETIME (100 nanosecond ticks since 1601)<br/>
+    /// Times before 1601 result in an error, and precision below 100 nanoseconds is truncated
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::new(1_700_000_000, 1_234_500), UNIX_EPOCH);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_filetime_le(my_simple_tuple.0).unwrap();
+    /// writer.write_filetime_le(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_filetime_le().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_filetime_le().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_filetime_le(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_u64_le(time::to_filetime(value)?);
+        Ok(())
+    }
+
+    /// For writing a UUID given in the RFC 4122 big-endian layout as a Microsoft GUID, where the first three fields are little-endian<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ([0x67, 0xE5, 0x50, 0x44, 0x10, 0xB1, 0x42, 0x6F, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0, 0xC8], [0xFF; 16]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_guid_le(my_simple_tuple.0);
+    /// writer.write_guid_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_guid_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_guid_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_guid_le(&mut self, value: [u8; 16]) {
+        self.write_u32_le(u32::from_be_bytes([value[0], value[1], value[2], value[3]]));
+        self.write_u16_le(u16::from_be_bytes([value[4], value[5]]));
+        self.write_u16_le(u16::from_be_bytes([value[6], value[7]]));
+        self.put_bytes(&value[8..16]);
+    }
+
+    #[cfg(feature = "uuid")]
+    /// For writing a `Uuid` as a Microsoft GUID, where the first three fields are little-endian<br/>
+    /// # Examples
+    /// ```
+    /// use uuid::Uuid;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (Uuid::from_u128(0x67E55044_10B1_426F_9247_BB680E5FE0C8), Uuid::nil());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_guid_typed_le(my_simple_tuple.0);
+    /// writer.write_guid_typed_le(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_guid_typed_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_guid_typed_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_guid_typed_le(&mut self, value: Uuid) {
+        self.write_guid_le(value.into_bytes())
+    }
+
+    /// For writing a `f64` as a signed Q16.16 fixed-point number in little-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.5f64, -2.25f64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_q16_16_le(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_q16_16_le(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_q16_16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_q16_16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_q16_16_le(&mut self, value: f64, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value, 65536.0, i32::MIN as i64, i32::MAX as i64, mode)?;
+        self.write_i32_le(fixed as i32);
+        Ok(())
+    }
+
+    /// For writing a `f32` as a signed 2.14 fixed-point number (F2DOT14) in little-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.5f32, -0.25f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f2dot14_le(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_f2dot14_le(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f2dot14_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f2dot14_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f2dot14_le(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, 16384.0, i16::MIN as i64, i16::MAX as i64, mode)?;
+        self.write_i16_le(fixed as i16);
+        Ok(())
+    }
+
+    /// For writing a `f32` from `0.0` to `1.0` as an unsigned normalised 16-bit integer (UNORM16) in little-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, 0.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_unorm16_le(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_unorm16_le(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_unorm16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unorm16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_unorm16_le(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, u16::MAX as f64, 0, u16::MAX as i64, mode)?;
+        self.write_u16_le(fixed as u16);
+        Ok(())
+    }
+
+    /// For writing a `f32` from `-1.0` to `1.0` as a signed normalised 16-bit integer (SNORM16) in little-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::fixed::FixedMode;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1.0f32, -1.0f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_snorm16_le(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_snorm16_le(my_simple_tuple.1, FixedMode::STRICT).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_snorm16_le(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_snorm16_le(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_snorm16_le(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, i16::MAX as f64, -(i16::MAX as i64), i16::MAX as i64, mode)?;
+        self.write_i16_le(fixed as i16);
+        Ok(())
+    }
+
+    /// For writing an unsigned `u64` in [`nbytes`] little-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (0x010203u64, 42u64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_uint_le(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_uint_le(my_simple_tuple.1, 3).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_uint_le(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint_le(3).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_uint_le(&mut self, value: u64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_unsigned(value as u128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, false);
+        Ok(())
+    }
+
+    /// For writing a signed `i64` in [`nbytes`] little-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (-2i64, 0x7FFFFFi64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_int_le(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_int_le(my_simple_tuple.1, 3).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_int_le(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int_le(3).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_int_le(&mut self, value: i64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_signed(value as i128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as i128 as u128, nbytes, false);
+        Ok(())
+    }
+
+    /// For writing an unsigned `u128` in [`nbytes`] little-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (1u128 << 100, 7u128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_uint128_le(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_uint128_le(my_simple_tuple.1, 13).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_uint128_le(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint128_le(13).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_uint128_le(&mut self, value: u128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_unsigned(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value, nbytes, false);
+        Ok(())
+    }
+
+    /// For writing a signed `i128` in [`nbytes`] little-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (-(1i128 << 100), 7i128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_int128_le(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_int128_le(my_simple_tuple.1, 13).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_int128_le(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int128_le(13).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_int128_le(&mut self, value: i128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_signed(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, false);
+        Ok(())
+    }
+
+    /// For encoding a [`HyperEncode`] value in little-endian, with `u32` length prefixes<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{LittleEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (7u32, vec![1.5f32, -2.0]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.encode_le(&my_simple_tuple).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.decode_le::<(u32, Vec<f32>)>().unwrap(), my_simple_tuple);
+    /// ```
+    #[inline(always)]
+    fn encode_le<T: HyperEncode<LittleEndian> + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        EndianWriter::<LittleEndian>::encode(self, value)
+    }
+}
+
+pub trait BigEndianByteWriter: ByteWriter {
+    /// For writing a byte in big-endian order<br/>
+    /// It is not recommended to use this unless the value in particular is quite literally one byte
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193u8, 22u8);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u8_be(my_simple_tuple.0);
+    /// writer.write_u8_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u8_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u8_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u8_be(&mut self, value: u8) {
+        self.put_bytes(&[value.to_be()])
+    }
+
+    /// For writing a `u16` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193u16, 22u16);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u16_be(my_simple_tuple.0);
+    /// writer.write_u16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u16_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u16_be(&mut self, value: u16) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `u32` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193u32, 22u32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u32_be(my_simple_tuple.0);
+    /// writer.write_u32_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u32_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u32_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u32_be(&mut self, value: u32) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `u64` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193u64, 22u64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u64_be(my_simple_tuple.0);
+    /// writer.write_u64_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u64_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u64_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u64_be(&mut self, value: u64) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `u128` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193u128, 22u128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_u128_be(my_simple_tuple.0);
+    /// writer.write_u128_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_u128_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_u128_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_u128_be(&mut self, value: u128) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `usize` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193usize, 22usize);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_usize_be(my_simple_tuple.0);
+    /// writer.write_usize_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_usize_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_usize_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_usize_be(&mut self, value: usize) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing an `i8` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (19i8, 22i8);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i8_be(my_simple_tuple.0);
+    /// writer.write_i8_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i8_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i8_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i8_be(&mut self, value: i8) {
+        self.put_bytes(&[(value as u8).to_be()])
+    }
+
+    /// For writing a `i16` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193i16, 22i16);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i16_be(my_simple_tuple.0);
+    /// writer.write_i16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i16_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i16_be(&mut self, value: i16) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `i32` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193i32, 22i32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i32_be(my_simple_tuple.0);
+    /// writer.write_i32_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i32_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i32_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i32_be(&mut self, value: i32) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `i64` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193i64, 22i64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i64_be(my_simple_tuple.0);
+    /// writer.write_i64_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i64_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i64_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i64_be(&mut self, value: i64) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `i128` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193i128, 22i128);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_i128_be(my_simple_tuple.0);
+    /// writer.write_i128_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_i128_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_i128_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_i128_be(&mut self, value: i128) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `isize` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193isize, 22isize);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_isize_be(my_simple_tuple.0);
+    /// writer.write_isize_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_isize_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_isize_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_isize_be(&mut self, value: isize) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f16` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use half::f16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (f16::from_f32_const(133.0f32), f16::from_f32_const(13.0f32));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f16_be(my_simple_tuple.0);
+    /// writer.write_f16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f16_be(&mut self, value: f16) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `bf16` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use half::bf16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (bf16::from_f32_const(133.0f32), bf16::from_f32_const(13.0f32));
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bf16_be(my_simple_tuple.0);
+    /// writer.write_bf16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bf16_be(&mut self, value: bf16) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `f16` in big-endian order, rounding to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_as_f16_be(my_simple_tuple.0);
+    /// writer.write_f32_as_f16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f16_as_f32_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_as_f32_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_as_f16_be(&mut self, value: f32) {
+        self.write_f16_be(f16::from_f32(value))
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a `f32` as a `bf16` in big-endian order, rounding to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (133.0f32, 13.5f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_as_bf16_be(my_simple_tuple.0);
+    /// writer.write_f32_as_bf16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_as_f32_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_as_f32_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_as_bf16_be(&mut self, value: f32) {
+        self.write_bf16_be(bf16::from_f32(value))
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `f16`s in big-endian order, rounding each to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u128, 22u128);
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u128_le(my_simple_tuple.0);
-    /// writer.write_u128_le(my_simple_tuple.1);
+    /// writer.write_f32_slice_as_f16_be(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_f16_be(&my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u128_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u128_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f16_slice_as_f32_be(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f16_slice_as_f32_be(2), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_slice_as_f16_be(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_f16_be(value));
+    }
+
+    #[cfg(feature = "half")]
+    /// For writing a slice of `f32`s as `bf16`s in big-endian order, rounding each to the nearest representable value<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = ([133.0f32, 13.5f32], [0.25f32, -2.0f32]);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_slice_as_bf16_be(&my_simple_tuple.0);
+    /// writer.write_f32_slice_as_bf16_be(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_bf16_slice_as_f32_be(2), my_simple_tuple.0);
+    /// assert_eq!(reader.read_bf16_slice_as_f32_be(2), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_slice_as_bf16_be(&mut self, values: &[f32]) {
+        values.iter().for_each(|&value| self.write_f32_as_bf16_be(value));
+    }
+
+    /// For writing a `f32` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193.32f32, 22.13f32);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f32_be(my_simple_tuple.0);
+    /// writer.write_f32_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f32_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f32_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f32_be(&mut self, value: f32) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a `f64` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193.6f64, 22.3f64);
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_f64_be(my_simple_tuple.0);
+    /// writer.write_f64_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_f64_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f64_be(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_f64_be(&mut self, value: f64) {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// For writing a set of native-endian bytes into big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
+    ///
+    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_bytes_be(&my_simple_tuple.0);
+    /// writer.write_bytes_be(&my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_n_be(my_simple_tuple.0.len()), my_simple_tuple.0);
+    /// assert_eq!(reader.read_n_be(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_bytes_be(&mut self, value: &[u8]) {
+        self.put_bytes(value)
+    }
+
+    /// For writing a string as UTF-16 code units in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_utf16_be(my_simple_tuple.0);
+    /// writer.write_utf16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_utf16_be(5).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_utf16_be(7).unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_utf16_be(&mut self, value: &str) {
+        value.encode_utf16().for_each(|unit| self.write_u16_be(unit));
+    }
+
+    /// For writing a string as UTF-16 code units in big-endian order, prefixed by the amount of code units as a `u16`<br/>
+    /// Returns an error if the string needs more than [`u16::MAX`] code units
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ("hyper", "bÿte 🦀");
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_utf16_be_prefixed(my_simple_tuple.0).unwrap();
+    /// writer.write_utf16_be_prefixed(my_simple_tuple.1).unwrap();
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_utf16_be_prefixed().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_utf16_be_prefixed().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_utf16_be_prefixed(&mut self, value: &str) -> Result<(), ByteError> {
+        let len_units = value.encode_utf16().count();
+        let prefix = u16::try_from(len_units).map_err(|_| ByteError::TooLong {
+            length: len_units,
+            maximum: u16::MAX as usize,
+        })?;
+        self.write_u16_be(prefix);
+        self.write_utf16_be(value);
+        Ok(())
+    }
+
+    /// For writing a `char` as its 4 byte scalar value in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = ('h', '🦀');
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_char_be(my_simple_tuple.0);
+    /// writer.write_char_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_char_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_char_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_char_be(&mut self, value: char) {
+        self.write_u32_be(value as u32)
+    }
+
+    /// For writing a `NonZeroU8` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU8::new(93u8).unwrap(), NonZeroU8::new(22u8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u8_be(my_simple_tuple.0);
+    /// writer.write_nonzero_u8_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u8_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u8_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u8_be(&mut self, value: NonZeroU8) {
+        self.write_u8_be(value.get())
+    }
+
+    /// For writing a `NonZeroU16` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU16;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU16::new(93u16).unwrap(), NonZeroU16::new(22u16).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u16_be(my_simple_tuple.0);
+    /// writer.write_nonzero_u16_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u16_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u16_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u16_be(&mut self, value: NonZeroU16) {
+        self.write_u16_be(value.get())
+    }
+
+    /// For writing a `NonZeroU32` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU32;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU32::new(93u32).unwrap(), NonZeroU32::new(22u32).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u32_be(my_simple_tuple.0);
+    /// writer.write_nonzero_u32_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u32_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u32_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u32_be(&mut self, value: NonZeroU32) {
+        self.write_u32_be(value.get())
+    }
+
+    /// For writing a `NonZeroU64` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU64;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU64::new(93u64).unwrap(), NonZeroU64::new(22u64).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u64_be(my_simple_tuple.0);
+    /// writer.write_nonzero_u64_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u64_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u64_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u64_be(&mut self, value: NonZeroU64) {
+        self.write_u64_be(value.get())
+    }
+
+    /// For writing a `NonZeroU128` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU128;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroU128::new(93u128).unwrap(), NonZeroU128::new(22u128).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_u128_be(my_simple_tuple.0);
+    /// writer.write_nonzero_u128_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_u128_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_u128_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_u128_be(&mut self, value: NonZeroU128) {
+        self.write_u128_be(value.get())
+    }
+
+    /// For writing a `NonZeroUsize` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroUsize::new(93usize).unwrap(), NonZeroUsize::new(22usize).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_usize_be(my_simple_tuple.0);
+    /// writer.write_nonzero_usize_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_usize_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_usize_be().unwrap(), my_simple_tuple.1);
+    /// ```
+    #[inline(always)]
+    fn write_nonzero_usize_be(&mut self, value: NonZeroUsize) {
+        self.write_usize_be(value.get())
+    }
+
+    /// For writing a `NonZeroI8` in big-endian order<br/>
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroI8;
+    /// use hyper_byte::reader::FastByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::FastByteWriter;
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
+    ///
+    /// let my_simple_tuple = (NonZeroI8::new(93i8).unwrap(), NonZeroI8::new(22i8).unwrap());
+    ///
+    /// let mut writer = FastByteWriter::new();
+    /// writer.write_nonzero_i8_be(my_simple_tuple.0);
+    /// writer.write_nonzero_i8_be(my_simple_tuple.1);
+    ///
+    /// let buffer = writer.to_vec();
+    /// let mut reader = FastByteReader::new(&buffer);
+    ///
+    /// assert_eq!(reader.read_nonzero_i8_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i8_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u128_le(&mut self, value: u128) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_nonzero_i8_be(&mut self, value: NonZeroI8) {
+        self.write_i8_be(value.get())
     }
 
-    /// For writing a `usize` in little-endian order<br/>
+    /// For writing a `NonZeroI16` in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use core::num::NonZeroI16;
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193usize, 22usize);
+    /// let my_simple_tuple = (NonZeroI16::new(93i16).unwrap(), NonZeroI16::new(22i16).unwrap());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_usize_le(my_simple_tuple.0);
-    /// writer.write_usize_le(my_simple_tuple.1);
+    /// writer.write_nonzero_i16_be(my_simple_tuple.0);
+    /// writer.write_nonzero_i16_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_usize_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_usize_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_nonzero_i16_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i16_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_usize_le(&mut self, value: usize) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_nonzero_i16_be(&mut self, value: NonZeroI16) {
+        self.write_i16_be(value.get())
     }
 
-    /// For writing an `i8` in little-endian order<br/>
+    /// For writing a `NonZeroI32` in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use core::num::NonZeroI32;
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
     ///
-    /// let my_simple_tuple = (19i8, 22i8);
+    /// let my_simple_tuple = (NonZeroI32::new(93i32).unwrap(), NonZeroI32::new(22i32).unwrap());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i8_le(my_simple_tuple.0);
-    /// writer.write_i8_le(my_simple_tuple.1);
+    /// writer.write_nonzero_i32_be(my_simple_tuple.0);
+    /// writer.write_nonzero_i32_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i8_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i8_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_nonzero_i32_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i32_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i8_le(&mut self, value: i8) {
-        self.as_mut_vec().push((value as u8).to_le())
+    fn write_nonzero_i32_be(&mut self, value: NonZeroI32) {
+        self.write_i32_be(value.get())
     }
 
-    /// For writing a `i16` in little-endian order<br/>
+    /// For writing a `NonZeroI64` in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use core::num::NonZeroI64;
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193i16, 22i16);
+    /// let my_simple_tuple = (NonZeroI64::new(93i64).unwrap(), NonZeroI64::new(22i64).unwrap());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i16_le(my_simple_tuple.0);
-    /// writer.write_i16_le(my_simple_tuple.1);
+    /// writer.write_nonzero_i64_be(my_simple_tuple.0);
+    /// writer.write_nonzero_i64_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i16_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i16_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_nonzero_i64_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i64_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i16_le(&mut self, value: i16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_nonzero_i64_be(&mut self, value: NonZeroI64) {
+        self.write_i64_be(value.get())
     }
 
-    /// For writing a `i32` in little-endian order<br/>
+    /// For writing a `NonZeroI128` in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use core::num::NonZeroI128;
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193i32, 22i32);
+    /// let my_simple_tuple = (NonZeroI128::new(93i128).unwrap(), NonZeroI128::new(22i128).unwrap());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i32_le(my_simple_tuple.0);
-    /// writer.write_i32_le(my_simple_tuple.1);
+    /// writer.write_nonzero_i128_be(my_simple_tuple.0);
+    /// writer.write_nonzero_i128_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i32_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i32_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_nonzero_i128_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_i128_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i32_le(&mut self, value: i32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_nonzero_i128_be(&mut self, value: NonZeroI128) {
+        self.write_i128_be(value.get())
     }
 
-    /// For writing a `i64` in little-endian order<br/>
+    /// For writing a `NonZeroIsize` in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use core::num::NonZeroIsize;
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{ByteWriter, BigEndianByteWriter};
     ///
-    /// let my_simple_tuple = (193i64, 22i64);
+    /// let my_simple_tuple = (NonZeroIsize::new(93isize).unwrap(), NonZeroIsize::new(22isize).unwrap());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i64_le(my_simple_tuple.0);
-    /// writer.write_i64_le(my_simple_tuple.1);
+    /// writer.write_nonzero_isize_be(my_simple_tuple.0);
+    /// writer.write_nonzero_isize_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i64_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i64_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_nonzero_isize_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_nonzero_isize_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i64_le(&mut self, value: i64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_nonzero_isize_be(&mut self, value: NonZeroIsize) {
+        self.write_isize_be(value.get())
     }
 
-    /// For writing a `i128` in little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing an `Ipv4Addr` in network order<br/>
     /// # Examples
     /// ```
-    /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
-    /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use std::net::Ipv4Addr;
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::NetworkWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193i128, 22i128);
+    /// let my_simple_tuple = (Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::LOCALHOST);
     ///
-    /// let mut writer = FastByteWriter::new();
-    /// writer.write_i128_le(my_simple_tuple.0);
-    /// writer.write_i128_le(my_simple_tuple.1);
+    /// let mut writer = NetworkWriter::new();
+    /// writer.write_ipv4(my_simple_tuple.0);
+    /// writer.write_ipv4(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
-    /// let mut reader = FastByteReader::new(&buffer);
+    /// let mut reader = NetworkReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i128_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i128_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_ipv4(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_ipv4(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i128_le(&mut self, value: i128) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_ipv4(&mut self, value: Ipv4Addr) {
+        self.write_u32_be(value.to_bits())
     }
 
-    /// For writing a `isize` in little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing an `Ipv6Addr` in network order<br/>
     /// # Examples
     /// ```
-    /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
-    /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use std::net::Ipv6Addr;
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::NetworkWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193isize, 22isize);
+    /// let my_simple_tuple = (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), Ipv6Addr::LOCALHOST);
     ///
-    /// let mut writer = FastByteWriter::new();
-    /// writer.write_isize_le(my_simple_tuple.0);
-    /// writer.write_isize_le(my_simple_tuple.1);
+    /// let mut writer = NetworkWriter::new();
+    /// writer.write_ipv6(my_simple_tuple.0);
+    /// writer.write_ipv6(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
-    /// let mut reader = FastByteReader::new(&buffer);
+    /// let mut reader = NetworkReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_isize_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_isize_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_ipv6(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_ipv6(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_isize_le(&mut self, value: isize) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_ipv6(&mut self, value: Ipv6Addr) {
+        self.write_u128_be(value.to_bits())
     }
 
-    #[cfg(feature = "half")]
-    /// For writing a `f16` in little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SocketAddrV4` as its address followed by a big-endian port<br/>
     /// # Examples
     /// ```
-    /// use half::f16;
-    /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
-    /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::NetworkWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (f16::from_f32_const(133.0f32), f16::from_f32_const(13.0f32));
+    /// let my_simple_tuple = (SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080), SocketAddrV4::new(Ipv4Addr::LOCALHOST, 443));
     ///
-    /// let mut writer = FastByteWriter::new();
-    /// writer.write_f16_le(my_simple_tuple.0);
-    /// writer.write_f16_le(my_simple_tuple.1);
+    /// let mut writer = NetworkWriter::new();
+    /// writer.write_socket_addr_v4(my_simple_tuple.0);
+    /// writer.write_socket_addr_v4(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
-    /// let mut reader = FastByteReader::new(&buffer);
+    /// let mut reader = NetworkReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f16_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f16_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_socket_addr_v4(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_socket_addr_v4(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f16_le(&mut self, value: f16) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_socket_addr_v4(&mut self, value: SocketAddrV4) {
+        self.write_ipv4(*value.ip());
+        self.write_u16_be(value.port());
     }
 
-    /// For writing a `f32` in little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SocketAddrV6` as its address followed by a big-endian port<br/>
+    /// The flow info and scope id are not written
     /// # Examples
     /// ```
-    /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
-    /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use std::net::{Ipv6Addr, SocketAddrV6};
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::NetworkWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.32f32, 22.13f32);
+    /// let my_simple_tuple = (SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 8080, 0, 0), SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0));
     ///
-    /// let mut writer = FastByteWriter::new();
-    /// writer.write_f32_le(my_simple_tuple.0);
-    /// writer.write_f32_le(my_simple_tuple.1);
+    /// let mut writer = NetworkWriter::new();
+    /// writer.write_socket_addr_v6(my_simple_tuple.0);
+    /// writer.write_socket_addr_v6(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
-    /// let mut reader = FastByteReader::new(&buffer);
+    /// let mut reader = NetworkReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f32_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f32_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_socket_addr_v6(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_socket_addr_v6(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f32_le(&mut self, value: f32) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_socket_addr_v6(&mut self, value: SocketAddrV6) {
+        self.write_ipv6(*value.ip());
+        self.write_u16_be(value.port());
     }
 
-    /// For writing a `f64` in little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a 6 byte MAC address<br/>
     /// # Examples
     /// ```
-    /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
-    /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::reader::NetworkReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
+    /// use hyper_byte::writer::NetworkWriter;
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64, 22.3f64);
+    /// let my_simple_tuple = ([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E], [0xFF; 6]);
     ///
-    /// let mut writer = FastByteWriter::new();
-    /// writer.write_f64_le(my_simple_tuple.0);
-    /// writer.write_f64_le(my_simple_tuple.1);
+    /// let mut writer = NetworkWriter::new();
+    /// writer.write_mac(my_simple_tuple.0);
+    /// writer.write_mac(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
-    /// let mut reader = FastByteReader::new(&buffer);
+    /// let mut reader = NetworkReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f64_le(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f64_le(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_mac(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_mac(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f64_le(&mut self, value: f64) {
-        self.as_mut_vec().extend(value.to_le_bytes())
+    fn write_mac(&mut self, value: [u8; 6]) {
+        self.put_bytes(&value)
     }
 
-    /// For writing a set of native-endian bytes into little-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u32` of whole seconds since the Unix epoch in big-endian order<br/>
+    /// Times before the epoch or after 2106 result in an error
     /// # Examples
     /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
     /// use hyper_byte::reader::FastByteReader;
-    /// use hyper_byte::readers::traits::LittleEndianByteReader;
+    /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
-    /// use hyper_byte::writers::traits::{ByteWriter, LittleEndianByteWriter};
+    /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_secs(1_700_000_000), UNIX_EPOCH);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_bytes_le(&my_simple_tuple.0);
-    /// writer.write_bytes_le(&my_simple_tuple.1);
+    /// writer.write_unix_secs_u32_be(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_u32_be(my_simple_tuple.1).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_n_le(my_simple_tuple.0.len()), my_simple_tuple.0);
-    /// assert_eq!(reader.read_n_le(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// assert_eq!(reader.read_unix_secs_u32_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_u32_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_bytes_le(&mut self, value: &[u8]) {
-        self.as_mut_vec().extend(value.iter().map(|x| x.to_le()))
+    fn write_unix_secs_u32_be(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        let seconds = time::to_unix(value)?.as_secs();
+        self.write_u32_be(u32::try_from(seconds).map_err(|_| ByteError::OutOfRange)?);
+        Ok(())
     }
-}
 
-pub trait BigEndianByteWriter: ByteWriter {
-    /// For writing a byte in big-endian order<br/>
-    /// It is not recommended to use this unless the value in particular is quite literally one byte
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of whole seconds since the Unix epoch in big-endian order<br/>
+    /// Times before the epoch result in an error
     /// # Examples
     /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u8, 22u8);
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_secs(1_700_000_000), UNIX_EPOCH);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u8_be(my_simple_tuple.0);
-    /// writer.write_u8_be(my_simple_tuple.1);
+    /// writer.write_unix_secs_u64_be(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_u64_be(my_simple_tuple.1).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u8_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u8_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_unix_secs_u64_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_u64_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u8_be(&mut self, value: u8) {
-        self.as_mut_vec().push(value.to_be())
+    fn write_unix_secs_u64_be(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_u64_be(time::to_unix(value)?.as_secs());
+        Ok(())
     }
 
-    /// For writing a `u16` in big-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of seconds since the Unix epoch followed by a `u32` of nanoseconds in big-endian order<br/>
+    /// Times before the epoch result in an error
     /// # Examples
     /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u16, 22u16);
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789), UNIX_EPOCH);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u16_be(my_simple_tuple.0);
-    /// writer.write_u16_be(my_simple_tuple.1);
+    /// writer.write_unix_secs_nanos_be(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_secs_nanos_be(my_simple_tuple.1).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u16_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u16_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_unix_secs_nanos_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_secs_nanos_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u16_be(&mut self, value: u16) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_unix_secs_nanos_be(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_duration_be(time::to_unix(value)?);
+        Ok(())
     }
 
-    /// For writing a `u32` in big-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a `u64` of whole milliseconds since the Unix epoch in big-endian order<br/>
+    /// Times before the epoch result in an error
     /// # Examples
     /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u32, 22u32);
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_millis(1_700_000_000_123), UNIX_EPOCH);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u32_be(my_simple_tuple.0);
-    /// writer.write_u32_be(my_simple_tuple.1);
+    /// writer.write_unix_millis_be(my_simple_tuple.0).unwrap();
+    /// writer.write_unix_millis_be(my_simple_tuple.1).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u32_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u32_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_unix_millis_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unix_millis_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u32_be(&mut self, value: u32) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_unix_millis_be(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        let millis = time::to_unix(value)?.as_millis();
+        self.write_u64_be(u64::try_from(millis).map_err(|_| ByteError::OutOfRange)?);
+        Ok(())
     }
 
-    /// For writing a `u64` in big-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `Duration` as a `u64` of seconds followed by a `u32` of nanoseconds in big-endian order<br/>
     /// # Examples
     /// ```
+    /// use std::time::Duration;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u64, 22u64);
+    /// let my_simple_tuple = (Duration::new(193, 22), Duration::MAX);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u64_be(my_simple_tuple.0);
-    /// writer.write_u64_be(my_simple_tuple.1);
+    /// writer.write_duration_be(my_simple_tuple.0);
+    /// writer.write_duration_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u64_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u64_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_duration_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_duration_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u64_be(&mut self, value: u64) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_duration_be(&mut self, value: Duration) {
+        self.write_u64_be(value.as_secs());
+        self.write_u32_be(value.subsec_nanos());
     }
 
-    /// For writing a `u128` in big-endian order<br/>
+    #[cfg(feature = "std")]
+    /// For writing a `SystemTime` as a 64-bit NTP timestamp (32.32 fixed-point seconds since 1900)<br/>
+    /// Times outside of NTP era 0, 1900 to 2036, result in an error
     /// # Examples
     /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193u128, 22u128);
+    /// let my_simple_tuple = (UNIX_EPOCH + Duration::from_secs(1_700_000_000), UNIX_EPOCH);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_u128_be(my_simple_tuple.0);
-    /// writer.write_u128_be(my_simple_tuple.1);
+    /// writer.write_ntp_timestamp_be(my_simple_tuple.0).unwrap();
+    /// writer.write_ntp_timestamp_be(my_simple_tuple.1).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_u128_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_u128_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_ntp_timestamp_be().unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_ntp_timestamp_be().unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_u128_be(&mut self, value: u128) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_ntp_timestamp_be(&mut self, value: SystemTime) -> Result<(), ByteError> {
+        self.write_u64_be(time::to_ntp(value)?);
+        Ok(())
     }
 
-    /// For writing a `usize` in big-endian order<br/>
+    /// For writing a UUID in its RFC 4122 big-endian layout<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -1015,149 +4237,168 @@ pub trait BigEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193usize, 22usize);
+    /// let my_simple_tuple = ([0x67, 0xE5, 0x50, 0x44, 0x10, 0xB1, 0x42, 0x6F, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0, 0xC8], [0xFF; 16]);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_usize_be(my_simple_tuple.0);
-    /// writer.write_usize_be(my_simple_tuple.1);
+    /// writer.write_uuid_be(my_simple_tuple.0);
+    /// writer.write_uuid_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_usize_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_usize_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_uuid_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uuid_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_usize_be(&mut self, value: usize) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_uuid_be(&mut self, value: [u8; 16]) {
+        self.write_u128_be(u128::from_be_bytes(value))
     }
 
-    /// For writing an `i8` in big-endian order<br/>
+    #[cfg(feature = "uuid")]
+    /// For writing a `Uuid` in its RFC 4122 big-endian layout<br/>
     /// # Examples
     /// ```
+    /// use uuid::Uuid;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (19i8, 22i8);
+    /// let my_simple_tuple = (Uuid::from_u128(0x67E55044_10B1_426F_9247_BB680E5FE0C8), Uuid::nil());
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i8_be(my_simple_tuple.0);
-    /// writer.write_i8_be(my_simple_tuple.1);
+    /// writer.write_uuid_typed_be(my_simple_tuple.0);
+    /// writer.write_uuid_typed_be(my_simple_tuple.1);
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i8_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i8_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_uuid_typed_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uuid_typed_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i8_be(&mut self, value: i8) {
-        self.as_mut_vec().push((value as u8).to_be())
+    fn write_uuid_typed_be(&mut self, value: Uuid) {
+        self.write_uuid_be(value.into_bytes())
     }
 
-    /// For writing a `i16` in big-endian order<br/>
+    /// For writing a `f64` as a signed Q16.16 fixed-point number in big-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
     /// # Examples
     /// ```
+    /// use hyper_byte::fixed::FixedMode;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193i16, 22i16);
+    /// let my_simple_tuple = (1.5f64, -2.25f64);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i16_be(my_simple_tuple.0);
-    /// writer.write_i16_be(my_simple_tuple.1);
+    /// writer.write_q16_16_be(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_q16_16_be(my_simple_tuple.1, FixedMode::STRICT).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i16_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i16_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_q16_16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_q16_16_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i16_be(&mut self, value: i16) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_q16_16_be(&mut self, value: f64, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value, 65536.0, i32::MIN as i64, i32::MAX as i64, mode)?;
+        self.write_i32_be(fixed as i32);
+        Ok(())
     }
 
-    /// For writing a `i32` in big-endian order<br/>
+    /// For writing a `f32` as a signed 2.14 fixed-point number (F2DOT14) in big-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
     /// # Examples
     /// ```
+    /// use hyper_byte::fixed::FixedMode;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193i32, 22i32);
+    /// let my_simple_tuple = (1.5f32, -0.25f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i32_be(my_simple_tuple.0);
-    /// writer.write_i32_be(my_simple_tuple.1);
+    /// writer.write_f2dot14_be(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_f2dot14_be(my_simple_tuple.1, FixedMode::STRICT).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i32_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i32_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_f2dot14_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_f2dot14_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i32_be(&mut self, value: i32) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_f2dot14_be(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, 16384.0, i16::MIN as i64, i16::MAX as i64, mode)?;
+        self.write_i16_be(fixed as i16);
+        Ok(())
     }
 
-    /// For writing a `i64` in big-endian order<br/>
+    /// For writing a `f32` from `0.0` to `1.0` as an unsigned normalised 16-bit integer (UNORM16) in big-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
     /// # Examples
     /// ```
+    /// use hyper_byte::fixed::FixedMode;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193i64, 22i64);
+    /// let my_simple_tuple = (1.0f32, 0.0f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i64_be(my_simple_tuple.0);
-    /// writer.write_i64_be(my_simple_tuple.1);
+    /// writer.write_unorm16_be(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_unorm16_be(my_simple_tuple.1, FixedMode::STRICT).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i64_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i64_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_unorm16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_unorm16_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i64_be(&mut self, value: i64) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_unorm16_be(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, u16::MAX as f64, 0, u16::MAX as i64, mode)?;
+        self.write_u16_be(fixed as u16);
+        Ok(())
     }
 
-    /// For writing a `i128` in big-endian order<br/>
+    /// For writing a `f32` from `-1.0` to `1.0` as a signed normalised 16-bit integer (SNORM16) in big-endian order<br/>
+    /// Rounding, and whether values out of range are clamped or result in an error, is decided by `mode`<br/>
     /// # Examples
     /// ```
+    /// use hyper_byte::fixed::FixedMode;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193i128, 22i128);
+    /// let my_simple_tuple = (1.0f32, -1.0f32);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_i128_be(my_simple_tuple.0);
-    /// writer.write_i128_be(my_simple_tuple.1);
+    /// writer.write_snorm16_be(my_simple_tuple.0, FixedMode::STRICT).unwrap();
+    /// writer.write_snorm16_be(my_simple_tuple.1, FixedMode::STRICT).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_i128_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_i128_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_snorm16_be(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_snorm16_be(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_i128_be(&mut self, value: i128) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_snorm16_be(&mut self, value: f32, mode: FixedMode) -> Result<(), ByteError> {
+        let fixed = quantize(value as f64, i16::MAX as f64, -(i16::MAX as i64), i16::MAX as i64, mode)?;
+        self.write_i16_be(fixed as i16);
+        Ok(())
     }
 
-    /// For writing a `isize` in big-endian order<br/>
+    /// For writing an unsigned `u64` in [`nbytes`] big-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -1165,51 +4406,59 @@ pub trait BigEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193isize, 22isize);
+    /// let my_simple_tuple = (0x010203u64, 42u64);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_isize_be(my_simple_tuple.0);
-    /// writer.write_isize_be(my_simple_tuple.1);
+    /// writer.write_uint_be(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_uint_be(my_simple_tuple.1, 3).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_isize_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_isize_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_uint_be(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint_be(3).unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_isize_be(&mut self, value: isize) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_uint_be(&mut self, value: u64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_unsigned(value as u128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, true);
+        Ok(())
     }
 
-    #[cfg(feature = "half")]
-    /// For writing a `f16` in big-endian order<br/>
+    /// For writing a signed `i64` in [`nbytes`] big-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 8 bytes or the value does not fit in it
     /// # Examples
     /// ```
-    /// use half::f16;
     /// use hyper_byte::reader::FastByteReader;
     /// use hyper_byte::readers::traits::BigEndianByteReader;
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (f16::from_f32_const(133.0f32), f16::from_f32_const(13.0f32));
+    /// let my_simple_tuple = (-2i64, 0x7FFFFFi64);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_f16_be(my_simple_tuple.0);
-    /// writer.write_f16_be(my_simple_tuple.1);
+    /// writer.write_int_be(my_simple_tuple.0, 3).unwrap();
+    /// writer.write_int_be(my_simple_tuple.1, 3).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f16_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f16_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_int_be(3).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int_be(3).unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f16_be(&mut self, value: f16) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_int_be(&mut self, value: i64, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 8 || !fits_signed(value as i128, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as i128 as u128, nbytes, true);
+        Ok(())
     }
 
-    /// For writing a `f32` in big-endian order<br/>
+    /// For writing an unsigned `u128` in [`nbytes`] big-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -1217,24 +4466,29 @@ pub trait BigEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.32f32, 22.13f32);
+    /// let my_simple_tuple = (1u128 << 100, 7u128);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_f32_be(my_simple_tuple.0);
-    /// writer.write_f32_be(my_simple_tuple.1);
+    /// writer.write_uint128_be(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_uint128_be(my_simple_tuple.1, 13).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f32_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f32_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_uint128_be(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_uint128_be(13).unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f32_be(&mut self, value: f32) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_uint128_be(&mut self, value: u128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_unsigned(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value, nbytes, true);
+        Ok(())
     }
 
-    /// For writing a `f64` in big-endian order<br/>
+    /// For writing a signed `i128` in [`nbytes`] big-endian bytes<br/>
+    /// Returns an error without writing anything if the width is more than 16 bytes or the value does not fit in it
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -1242,24 +4496,28 @@ pub trait BigEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64, 22.3f64);
+    /// let my_simple_tuple = (-(1i128 << 100), 7i128);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_f64_be(my_simple_tuple.0);
-    /// writer.write_f64_be(my_simple_tuple.1);
+    /// writer.write_int128_be(my_simple_tuple.0, 13).unwrap();
+    /// writer.write_int128_be(my_simple_tuple.1, 13).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_f64_be(), my_simple_tuple.0);
-    /// assert_eq!(reader.read_f64_be(), my_simple_tuple.1);
+    /// assert_eq!(reader.read_int128_be(13).unwrap(), my_simple_tuple.0);
+    /// assert_eq!(reader.read_int128_be(13).unwrap(), my_simple_tuple.1);
     /// ```
     #[inline(always)]
-    fn write_f64_be(&mut self, value: f64) {
-        self.as_mut_vec().extend(value.to_be_bytes())
+    fn write_int128_be(&mut self, value: i128, nbytes: usize) -> Result<(), ByteError> {
+        if nbytes > 16 || !fits_signed(value, nbytes) {
+            return Err(ByteError::OutOfRange);
+        }
+        write_uint_bytes(self, value as u128, nbytes, true);
+        Ok(())
     }
 
-    /// For writing a set of native-endian bytes into big-endian order<br/>
+    /// For encoding a [`HyperEncode`] value in big-endian, with `u32` length prefixes<br/>
     /// # Examples
     /// ```
     /// use hyper_byte::reader::FastByteReader;
@@ -1267,20 +4525,18 @@ pub trait BigEndianByteWriter: ByteWriter {
     /// use hyper_byte::writer::FastByteWriter;
     /// use hyper_byte::writers::traits::{BigEndianByteWriter, ByteWriter};
     ///
-    /// let my_simple_tuple = (193.6f64.to_ne_bytes(), 22.3f64.to_ne_bytes());
+    /// let my_simple_tuple = (7u32, vec![1.5f32, -2.0]);
     ///
     /// let mut writer = FastByteWriter::new();
-    /// writer.write_bytes_be(&my_simple_tuple.0);
-    /// writer.write_bytes_be(&my_simple_tuple.1);
+    /// writer.encode_be(&my_simple_tuple).unwrap();
     ///
     /// let buffer = writer.to_vec();
     /// let mut reader = FastByteReader::new(&buffer);
     ///
-    /// assert_eq!(reader.read_n_be(my_simple_tuple.0.len()), my_simple_tuple.0);
-    /// assert_eq!(reader.read_n_be(my_simple_tuple.0.len()), my_simple_tuple.1);
+    /// assert_eq!(reader.decode_be::<(u32, Vec<f32>)>().unwrap(), my_simple_tuple);
     /// ```
     #[inline(always)]
-    fn write_bytes_be(&mut self, value: &[u8]) {
-        self.as_mut_vec().extend(value.iter().map(|x| x.to_be()))
+    fn encode_be<T: HyperEncode<BigEndian> + ?Sized>(&mut self, value: &T) -> Result<(), ByteError> {
+        EndianWriter::<BigEndian>::encode(self, value)
     }
 }